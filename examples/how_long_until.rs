@@ -14,8 +14,12 @@ impl FromStr for DateDDMMYY {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut s2 = s.to_string();
         s2.push_str(" 00:00:00");
-        match Local.datetime_from_str(s2.trim(), "%Y/%m/%d %H:%M:%S") {
-            Ok(time) => Ok(DateDDMMYY(time)),
+        match NaiveDateTime::parse_from_str(s2.trim(), "%Y/%m/%d %H:%M:%S") {
+            Ok(time) => Local
+                .from_local_datetime(&time)
+                .single()
+                .map(DateDDMMYY)
+                .ok_or(()),
             Err(_) => Err(()),
         }
     }
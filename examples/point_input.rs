@@ -7,6 +7,7 @@ use read_input::prelude::*;
 use std::str::FromStr;
 
 #[derive(Debug)]
+#[allow(dead_code)]
 struct Point {
     x: i32,
     y: i32,
@@ -25,7 +26,7 @@ impl FromStr for Point {
         let clean_s = s
             .trim_matches(|p| p == '(' || p == ')')
             .trim()
-            .replace(|p| p == ' ', "");
+            .replace(' ', "");
         {
             if !clean_s.contains(|c| {
                 ['1', '2', '3', '4', '5', '6', '7', '8', '9', '0', ',', '-'].contains(&c)
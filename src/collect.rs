@@ -0,0 +1,140 @@
+//! Collecting many values by prompting once per value.
+//!
+//! A [`Collector`] is created with [`InputBuilder::collect_until_empty`],
+//! [`InputBuilder::collect_until`] or [`InputBuilder::collect_n`]. It runs the builder once per
+//! value and numbers the prompts.
+//!
+//! ```no_run
+//! # use read_input::prelude::*;
+//! let names: Vec<String> = input().msg("Name {n}: ").collect_until_empty().undo("undo").get();
+//! ```
+
+use crate::{
+    core::{parse_line, read_with_control, translated},
+    messages::Message,
+    template::render,
+    InputBuilder,
+};
+use std::{io, str::FromStr};
+
+// When to stop collecting.
+enum Until {
+    Line(String),
+    Count(usize),
+}
+
+// A line read by a `Collector`.
+enum Entry<T> {
+    Value(T),
+    Undo,
+    Done,
+}
+
+/// Prompts for values one after another and returns them as a `Vec`.
+///
+/// The prompt message of the builder can contain `{n}`, which is replaced with the number of
+/// the value being read. Without a prompt message `Item 1: `, `Item 2: ` and so on are used.
+pub struct Collector<T: FromStr> {
+    builder: InputBuilder<T>,
+    prompt: Option<String>,
+    until: Until,
+    max_items: Option<usize>,
+    undo: Option<String>,
+}
+
+impl<T: FromStr> Collector<T> {
+    fn new(builder: InputBuilder<T>, until: Until) -> Self {
+        let prompt = Some(builder.msg.msg.clone()).filter(|msg| !msg.is_empty());
+        Self {
+            builder,
+            prompt,
+            until,
+            max_items: None,
+            undo: None,
+        }
+    }
+    /// Stops once this many values have been collected.
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.max_items = Some(max);
+        self
+    }
+    /// Lets the user remove the previous value by typing `word`.
+    ///
+    /// Like the word that ends collecting, it is not added to history or echoed.
+    pub fn undo(mut self, word: impl ToString) -> Self {
+        self.undo = Some(word.to_string());
+        self
+    }
+    /// 'gets' the values form the user.
+    ///
+    /// Panics if unable to read input line.
+    pub fn get(self) -> Vec<T> {
+        self.try_get().expect("Failed to read line")
+    }
+    /// 'gets' the values form the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if unable to read input line.
+    pub fn try_get(mut self) -> io::Result<Vec<T>> {
+        let mut values = Vec::new();
+        loop {
+            let full = match self.until {
+                Until::Count(count) => values.len() >= count,
+                Until::Line(_) => false,
+            };
+            if full || self.max_items.is_some_and(|max| values.len() >= max) {
+                return Ok(values);
+            }
+            let n = values.len() + 1;
+            self.builder.msg.msg = match &self.prompt {
+                Some(prompt) => render(prompt, |name| (name == "n").then(|| n.to_string())),
+                None => translated(&self.builder, &Message::ItemPrompt(n)),
+            };
+            let control = |input: &str| {
+                let line = input.trim();
+                match &self.until {
+                    // The end of input also ends collecting.
+                    Until::Line(sentinel) if line == sentinel || input.is_empty() => {
+                        Some(Entry::Done)
+                    }
+                    _ if self.undo.as_deref() == Some(line) => Some(Entry::Undo),
+                    _ => None,
+                }
+            };
+            let parse = |input: &str, raw: Option<&[u8]>, attempt| {
+                parse_line(input, raw, &self.builder, attempt).map(Entry::Value)
+            };
+            let entry = read_with_control(&self.builder, None, None, control, parse)?;
+            match entry {
+                Entry::Value(value) => values.push(value),
+                Entry::Undo => {
+                    values.pop();
+                }
+                Entry::Done => return Ok(values),
+            }
+        }
+    }
+}
+
+impl<T: FromStr> InputBuilder<T> {
+    /// Prompts for values until the user submits an empty line or input ends.
+    pub fn collect_until_empty(self) -> Collector<T> {
+        Collector::new(self, Until::Line(String::new()))
+    }
+    /// Prompts for values until the user types `sentinel` or input ends.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// let scores: Vec<u32> = input().collect_until("done").max_items(10).get();
+    /// ```
+    pub fn collect_until(self, sentinel: impl ToString) -> Collector<T> {
+        Collector::new(self, Until::Line(sentinel.to_string()))
+    }
+    /// Prompts for exactly `count` values.
+    ///
+    /// In batch mode input that ends early is an error.
+    pub fn collect_n(self, count: usize) -> Collector<T> {
+        Collector::new(self, Until::Count(count))
+    }
+}
@@ -0,0 +1,101 @@
+//! Tab completion of input.
+//!
+//! Pressing Tab completes the text typed so far using the completers added with
+//! [`InputBuild::completer`](crate::InputBuild::completer) and the values given to
+//! [`InputBuild::select`](crate::InputBuild::select). It is completed as far as all matches
+//! agree and pressing Tab again lists the matches.
+//!
+//! On Unix the terminal is switched to raw mode while such a line is typed. Elsewhere, or when
+//! input is not typed on a terminal, a tab is read as part of the line, so a line typed as a
+//! prefix followed by Tab and then Enter is completed instead. A single match is taken as the
+//! input, several matches are listed and the user is asked again.
+
+use crate::history::History;
+use std::{fs, path::Path};
+
+/// Trait for types that suggest completions for partially typed input.
+///
+/// It is implemented for closures so simple completers can be written inline.
+///
+/// ```no_run
+/// # use read_input::prelude::*;
+/// let colour: String = input()
+///     .completer(|x: &str| {
+///         ["red", "green", "blue"]
+///             .iter()
+///             .filter(|c| c.starts_with(x))
+///             .map(|c| c.to_string())
+///             .collect()
+///     })
+///     .get();
+/// ```
+pub trait Completer {
+    /// Returns all values that complete `input`.
+    fn complete(&self, input: &str) -> Vec<String>;
+}
+
+impl<F: Fn(&str) -> Vec<String>> Completer for F {
+    fn complete(&self, input: &str) -> Vec<String> {
+        self(input)
+    }
+}
+
+/// Completes from a fixed list of values.
+#[derive(Clone, Debug)]
+pub struct Choices(pub Vec<String>);
+
+impl Completer for Choices {
+    fn complete(&self, input: &str) -> Vec<String> {
+        self.0
+            .iter()
+            .filter(|x| x.starts_with(input))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Completes from the entries of a [`History`], most recent first.
+impl Completer for History {
+    fn complete(&self, input: &str) -> Vec<String> {
+        let mut matches: Vec<String> = Vec::new();
+        for entry in self.entries().into_iter().rev() {
+            if entry.starts_with(input) && !matches.contains(&entry) {
+                matches.push(entry);
+            }
+        }
+        matches
+    }
+}
+
+/// Completes filesystem paths.
+///
+/// Directories are completed with a trailing `/`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PathCompleter;
+
+impl Completer for PathCompleter {
+    fn complete(&self, input: &str) -> Vec<String> {
+        let (dir, start) = match input.rfind(['/', std::path::MAIN_SEPARATOR]) {
+            Some(i) => input.split_at(i + 1),
+            None => ("", input),
+        };
+        let search = if dir.is_empty() { "." } else { dir };
+        let entries = match fs::read_dir(Path::new(search)) {
+            Ok(entries) => entries,
+            Err(_) => return Vec::new(),
+        };
+        let mut matches: Vec<String> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                if !name.starts_with(start) || (name.starts_with('.') && !start.starts_with('.')) {
+                    return None;
+                }
+                let slash = if entry.path().is_dir() { "/" } else { "" };
+                Some(format!("{}{}{}", dir, name, slash))
+            })
+            .collect();
+        matches.sort();
+        matches
+    }
+}
@@ -0,0 +1,667 @@
+//! Constraints for values that [`InputConstraints`](crate::InputConstraints) can not describe.
+//!
+//! [`PathConstraints`] checks paths on the file system.
+//!
+//! ```no_run
+//! # use read_input::prelude::*;
+//! use std::path::PathBuf;
+//! let config: PathBuf = input()
+//!     .msg("Config file: ")
+//!     .expand()
+//!     .is_file()
+//!     .extension(["toml", "json"])
+//!     .get();
+//! ```
+//!
+//! [`StringConstraints`] checks the length and characters of text.
+//!
+//! ```no_run
+//! # use read_input::prelude::*;
+//! let username: String = input().msg("Username: ").len_range(3..=16).alphanumeric().get();
+//! ```
+//!
+//! [`FloatConstraints`] checks floating point numbers.
+//!
+//! ```no_run
+//! # use read_input::prelude::*;
+//! let price: f64 = input().msg("Price: ").finite().min(0.0).max_decimal_places(2).get();
+//! ```
+
+use crate::{
+    list::ListBuilder, messages::Message, test_generators::InsideFunc, InputBuild, InputBuilder,
+    InputBuilderOnce,
+};
+use std::{
+    env,
+    ffi::OsString,
+    fmt::Display,
+    fs::{self, File, OpenOptions},
+    ops::{
+        Bound::{Excluded, Included, Unbounded},
+        RangeBounds,
+    },
+    path::{self, Path, PathBuf},
+    process,
+    rc::Rc,
+    str::FromStr,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+// Constraint made of a test and the message that describes it.
+struct Rule<T> {
+    func: Rc<dyn Fn(&T) -> bool>,
+    message: Message,
+    candidates: Option<Vec<String>>,
+}
+
+impl<T> Rule<T> {
+    fn new(func: impl Fn(&T) -> bool + 'static, message: Message) -> Self {
+        Self {
+            func: Rc::new(func),
+            message,
+            candidates: None,
+        }
+    }
+}
+
+impl<T> InsideFunc<T> for Rule<T> {
+    fn contains_func(self) -> Rc<dyn Fn(&T) -> bool> {
+        self.func
+    }
+    fn candidates(&self) -> Option<Vec<String>> {
+        self.candidates.clone()
+    }
+    fn message(&self) -> Option<Message> {
+        Some(self.message.clone())
+    }
+}
+
+/// A set of validation tests for paths.
+///
+/// Each constraint has an error message that describes it, such as "Must be a file.".
+/// The `_err` variants replace it with a custom error message.
+///
+/// Relative paths are checked against the current directory. Use [`PathConstraints::expand`]
+/// and [`PathConstraints::absolute`] to change paths before they are checked.
+// `is_file` and `is_dir` are named after the methods of `Path` they check with.
+#[allow(clippy::wrong_self_convention)]
+pub trait PathConstraints<T>: InputBuild<T>
+where
+    T: FromStr + AsRef<Path> + From<PathBuf> + 'static,
+    Self: Sized,
+{
+    /// Replaces a leading `~` with the home directory and `$VAR` or `${VAR}` with the value of
+    /// the environment variable. Variables that are not set are left as they are.
+    fn expand(self) -> Self;
+    /// Makes relative paths absolute by joining them to the current directory.
+    ///
+    /// The file system is not accessed, so `..` and symbolic links are kept.
+    fn absolute(self) -> Self;
+    /// Only accepts paths that exist.
+    fn must_exist(self) -> Self {
+        self.inside(Rule::new(|x: &T| x.as_ref().exists(), Message::Exists))
+    }
+    /// Only accepts paths that exist with custom error message.
+    fn must_exist_err(self, err: impl ToString) -> Self {
+        self.inside_err(Rule::new(|x: &T| x.as_ref().exists(), Message::Exists), err)
+    }
+    /// Only accepts paths that do not exist yet.
+    fn must_not_exist(self) -> Self {
+        self.inside(Rule::new(|x: &T| !exists(x.as_ref()), Message::NotExists))
+    }
+    /// Only accepts paths that do not exist yet with custom error message.
+    fn must_not_exist_err(self, err: impl ToString) -> Self {
+        self.inside_err(
+            Rule::new(|x: &T| !exists(x.as_ref()), Message::NotExists),
+            err,
+        )
+    }
+    /// Only accepts paths of existing files.
+    fn is_file(self) -> Self {
+        self.inside(Rule::new(|x: &T| x.as_ref().is_file(), Message::File))
+    }
+    /// Only accepts paths of existing files with custom error message.
+    fn is_file_err(self, err: impl ToString) -> Self {
+        self.inside_err(Rule::new(|x: &T| x.as_ref().is_file(), Message::File), err)
+    }
+    /// Only accepts paths of existing directories.
+    fn is_dir(self) -> Self {
+        self.inside(Rule::new(|x: &T| x.as_ref().is_dir(), Message::Directory))
+    }
+    /// Only accepts paths of existing directories with custom error message.
+    fn is_dir_err(self, err: impl ToString) -> Self {
+        self.inside_err(
+            Rule::new(|x: &T| x.as_ref().is_dir(), Message::Directory),
+            err,
+        )
+    }
+    /// Only accepts paths with one of these extensions. Case is ignored and a leading `.` is
+    /// optional.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// use std::path::PathBuf;
+    /// let image: PathBuf = input().extension(["png", ".jpg"]).get();
+    /// ```
+    fn extension<I>(self, extensions: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.inside(extension_rule(extensions))
+    }
+    /// Only accepts paths with one of these extensions with custom error message.
+    fn extension_err<I>(self, extensions: I, err: impl ToString) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.inside_err(extension_rule(extensions), err)
+    }
+    /// Only accepts files and directories that exist and can be read.
+    ///
+    /// Special files such as FIFOs are not accepted, because opening them to check can block.
+    fn readable(self) -> Self {
+        self.inside(Rule::new(|x: &T| readable(x.as_ref()), Message::Readable))
+    }
+    /// Only accepts paths that exist and can be read with custom error message.
+    fn readable_err(self, err: impl ToString) -> Self {
+        self.inside_err(
+            Rule::new(|x: &T| readable(x.as_ref()), Message::Readable),
+            err,
+        )
+    }
+    /// Only accepts files and directories that can be written to. Paths that do not exist are
+    /// accepted if their directory can be written to.
+    ///
+    /// Access is checked by opening the file for appending, or by creating and removing an
+    /// empty file in the directory. Special files such as FIFOs are not accepted.
+    fn writable(self) -> Self {
+        self.inside(Rule::new(|x: &T| writable(x.as_ref()), Message::Writable))
+    }
+    /// Only accepts paths that can be written to with custom error message.
+    fn writable_err(self, err: impl ToString) -> Self {
+        self.inside_err(
+            Rule::new(|x: &T| writable(x.as_ref()), Message::Writable),
+            err,
+        )
+    }
+}
+
+// Also counts broken symbolic links, which `Path::exists` does not.
+fn exists(path: &Path) -> bool {
+    fs::symlink_metadata(path).is_ok()
+}
+
+fn extension_rule<T, I>(extensions: I) -> Rule<T>
+where
+    T: AsRef<Path>,
+    I: IntoIterator,
+    I::Item: ToString,
+{
+    let extensions: Vec<String> = extensions
+        .into_iter()
+        .map(|x| x.to_string().trim_start_matches('.').to_string())
+        .collect();
+    let message = Message::Extension(extensions.clone());
+    Rule::new(
+        move |x: &T| {
+            let extension = x.as_ref().extension().and_then(|x| x.to_str());
+            extension.is_some_and(|extension| {
+                extensions.iter().any(|x| x.eq_ignore_ascii_case(extension))
+            })
+        },
+        message,
+    )
+}
+
+// Special files such as FIFOs are never opened, because opening them can block.
+fn readable(path: &Path) -> bool {
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.is_dir() => fs::read_dir(path).is_ok(),
+        Ok(metadata) if metadata.is_file() => File::open(path).is_ok(),
+        _ => false,
+    }
+}
+
+// Permission bits do not say whether this process may write, so writing is tried instead.
+fn writable(path: &Path) -> bool {
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.is_dir() => can_create_in(path),
+        // Appending without `create` leaves the file as it is.
+        Ok(metadata) if metadata.is_file() => OpenOptions::new().append(true).open(path).is_ok(),
+        Ok(_) => false,
+        Err(_) => match path.parent() {
+            Some(parent) if parent.as_os_str().is_empty() => can_create_in(Path::new(".")),
+            Some(parent) => parent.is_dir() && can_create_in(parent),
+            None => false,
+        },
+    }
+}
+
+// Creates and removes an empty file to find out if `dir` can be written to.
+fn can_create_in(dir: &Path) -> bool {
+    static PROBES: AtomicUsize = AtomicUsize::new(0);
+    let name = format!(
+        ".read_input-{}-{}",
+        process::id(),
+        PROBES.fetch_add(1, Ordering::Relaxed)
+    );
+    let probe = dir.join(name);
+    match OpenOptions::new().write(true).create_new(true).open(&probe) {
+        Ok(_) => {
+            let _ = fs::remove_file(&probe);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+// Expands `~` and environment variables in a path.
+fn expand(path: PathBuf) -> PathBuf {
+    expand_with(path, &|name| env::var_os(name))
+}
+
+// Expands `~` and variables looked up with `var`. Paths that are not valid unicode are left
+// as they are.
+pub(crate) fn expand_with(path: PathBuf, var: &dyn Fn(&str) -> Option<OsString>) -> PathBuf {
+    let text = match path.to_str() {
+        Some(text) => expand_vars(text, var),
+        None => return path,
+    };
+    let home = var(if cfg!(windows) { "USERPROFILE" } else { "HOME" }).filter(|x| !x.is_empty());
+    match (text.strip_prefix('~'), home) {
+        (Some(rest), Some(home)) if rest.is_empty() || rest.starts_with(path::is_separator) => {
+            let mut path = home;
+            path.push(rest);
+            path.into()
+        }
+        _ => text.into(),
+    }
+}
+
+fn expand_vars(text: &str, var: &dyn Fn(&str) -> Option<OsString>) -> String {
+    let mut expanded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('$') {
+        expanded.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+        let (name, len) = match rest.strip_prefix('{') {
+            Some(braced) => match braced.find('}') {
+                Some(end) => (&braced[..end], end + 2),
+                None => ("", 0),
+            },
+            None => {
+                let end = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                (&rest[..end], end)
+            }
+        };
+        let value = var(name).and_then(|value| value.into_string().ok());
+        match value.filter(|_| !name.is_empty()) {
+            Some(value) => expanded.push_str(&value),
+            None => {
+                expanded.push('$');
+                expanded.push_str(&rest[..len]);
+            }
+        }
+        rest = &rest[len..];
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+// Makes a path absolute, leaving it as it is if the current directory is not known.
+fn absolute(path: PathBuf) -> PathBuf {
+    path::absolute(&path).unwrap_or(path)
+}
+
+// Applies `f` to the path held by a value.
+fn on_path<T: AsRef<Path> + From<PathBuf>>(f: fn(PathBuf) -> PathBuf) -> impl Fn(T) -> T {
+    move |x| T::from(f(x.as_ref().to_path_buf()))
+}
+
+/// A set of validation tests for text that look at its length and characters.
+///
+/// Lengths are counted in characters rather than bytes. Each constraint has an error message
+/// that describes it, such as "Must be at least 3 characters long.". The `_err` variants
+/// replace it with a custom error message.
+pub trait StringConstraints<T>: InputBuild<T>
+where
+    T: FromStr + AsRef<str> + 'static,
+    Self: Sized,
+{
+    /// Only accepts text with at least `min` characters.
+    fn min_len(self, min: usize) -> Self {
+        self.inside(length_rule(min..))
+    }
+    /// Only accepts text with at least `min` characters with custom error message.
+    fn min_len_err(self, min: usize, err: impl ToString) -> Self {
+        self.inside_err(length_rule(min..), err)
+    }
+    /// Only accepts text with at most `max` characters.
+    fn max_len(self, max: usize) -> Self {
+        self.inside(length_rule(..=max))
+    }
+    /// Only accepts text with at most `max` characters with custom error message.
+    fn max_len_err(self, max: usize, err: impl ToString) -> Self {
+        self.inside_err(length_rule(..=max), err)
+    }
+    /// Only accepts text whose number of characters is inside `range`.
+    fn len_range(self, range: impl RangeBounds<usize>) -> Self {
+        self.inside(length_rule(range))
+    }
+    /// Only accepts text whose number of characters is inside `range` with custom error
+    /// message.
+    fn len_range_err(self, range: impl RangeBounds<usize>, err: impl ToString) -> Self {
+        self.inside_err(length_rule(range), err)
+    }
+    /// Only accepts text that is not empty.
+    fn non_empty(self) -> Self {
+        self.inside(Rule::new(|x: &T| !x.as_ref().is_empty(), Message::NotEmpty))
+    }
+    /// Only accepts text that is not empty with custom error message.
+    fn non_empty_err(self, err: impl ToString) -> Self {
+        self.inside_err(
+            Rule::new(|x: &T| !x.as_ref().is_empty(), Message::NotEmpty),
+            err,
+        )
+    }
+    /// Only accepts text made of ASCII characters.
+    fn ascii_only(self) -> Self {
+        self.inside(Rule::new(|x: &T| x.as_ref().is_ascii(), Message::Ascii))
+    }
+    /// Only accepts text made of ASCII characters with custom error message.
+    fn ascii_only_err(self, err: impl ToString) -> Self {
+        self.inside_err(
+            Rule::new(|x: &T| x.as_ref().is_ascii(), Message::Ascii),
+            err,
+        )
+    }
+    /// Only accepts text made of letters and digits.
+    fn alphanumeric(self) -> Self {
+        self.inside(alphanumeric_rule())
+    }
+    /// Only accepts text made of letters and digits with custom error message.
+    fn alphanumeric_err(self, err: impl ToString) -> Self {
+        self.inside_err(alphanumeric_rule(), err)
+    }
+    /// Only accepts text that matches `regex` somewhere. Use `^` and `$` to match all of it.
+    ///
+    /// Only available with the `regex` feature.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// use regex::Regex;
+    /// let code: String = input().matches(Regex::new("^[A-Z]{3}-[0-9]{4}$").unwrap()).get();
+    /// ```
+    #[cfg(feature = "regex")]
+    fn matches(self, regex: regex::Regex) -> Self {
+        self.inside(regex_rule(regex))
+    }
+    /// Only accepts text that matches `regex` somewhere with custom error message.
+    ///
+    /// Only available with the `regex` feature.
+    #[cfg(feature = "regex")]
+    fn matches_err(self, regex: regex::Regex, err: impl ToString) -> Self {
+        self.inside_err(regex_rule(regex), err)
+    }
+    /// Only accepts one of `values`, ignoring case. The text is returned as it was typed.
+    ///
+    /// Like [`InputBuild::select`], the values are offered as completions
+    /// and close misses get a suggestion.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// let colour: String = input().one_of_ignore_case(["red", "green", "blue"]).get();
+    /// ```
+    fn one_of_ignore_case<I>(self, values: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.inside(one_of_rule(values))
+    }
+    /// Only accepts one of `values`, ignoring case, with custom error message.
+    fn one_of_ignore_case_err<I>(self, values: I, err: impl ToString) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.inside_err(one_of_rule(values), err)
+    }
+}
+
+fn length_rule<T: AsRef<str>>(range: impl RangeBounds<usize>) -> Rule<T> {
+    let min = match range.start_bound() {
+        Included(&min) => min,
+        Excluded(&min) => min.saturating_add(1),
+        Unbounded => 0,
+    };
+    let max = match range.end_bound() {
+        Included(&max) => Some(max),
+        Excluded(&max) => Some(max.saturating_sub(1)),
+        Unbounded => None,
+    };
+    let message = match max {
+        None => Message::MinLength(min),
+        Some(max) if min == 0 => Message::MaxLength(max),
+        Some(max) => Message::LengthBetween(min, max),
+    };
+    Rule::new(
+        move |x: &T| {
+            let len = x.as_ref().chars().count();
+            len >= min && max.filter(|&max| len > max).is_none()
+        },
+        message,
+    )
+}
+
+fn alphanumeric_rule<T: AsRef<str>>() -> Rule<T> {
+    Rule::new(
+        |x: &T| x.as_ref().chars().all(char::is_alphanumeric),
+        Message::Alphanumeric,
+    )
+}
+
+#[cfg(feature = "regex")]
+fn regex_rule<T: AsRef<str>>(regex: regex::Regex) -> Rule<T> {
+    let message = Message::Matches(regex.as_str().to_string());
+    Rule::new(move |x: &T| regex.is_match(x.as_ref()), message)
+}
+
+fn one_of_rule<T, I>(values: I) -> Rule<T>
+where
+    T: AsRef<str>,
+    I: IntoIterator,
+    I::Item: ToString,
+{
+    let values: Vec<String> = values.into_iter().map(|x| x.to_string()).collect();
+    let lowercase: Vec<String> = values.iter().map(|x| x.to_lowercase()).collect();
+    let mut rule = Rule::new(
+        move |x: &T| lowercase.contains(&x.as_ref().to_lowercase()),
+        Message::OneOf(values.clone()),
+    );
+    rule.candidates = Some(values);
+    rule
+}
+
+/// A set of validation tests for floating point numbers.
+///
+/// `NaN` and infinite values parse successfully and infinity passes
+/// [`InputConstraints::min`](crate::InputConstraints::min), so prompts for amounts usually
+/// want [`FloatConstraints::finite`]. Each constraint has an error message that
+/// describes it, such as "Must be a multiple of 0.25.". The `_err` variants replace it with a
+/// custom error message.
+pub trait FloatConstraints<T>: InputBuild<T>
+where
+    T: FromStr + Into<f64> + Copy + Display + 'static,
+    Self: Sized,
+{
+    /// Only accepts numbers that are not infinite or `NaN`.
+    fn finite(self) -> Self {
+        self.inside(finite_rule())
+    }
+    /// Only accepts numbers that are not infinite or `NaN` with custom error message.
+    fn finite_err(self, err: impl ToString) -> Self {
+        self.inside_err(finite_rule(), err)
+    }
+    /// Only accepts numbers that are not `NaN`.
+    fn not_nan(self) -> Self {
+        self.inside(not_nan_rule())
+    }
+    /// Only accepts numbers that are not `NaN` with custom error message.
+    fn not_nan_err(self, err: impl ToString) -> Self {
+        self.inside_err(not_nan_rule(), err)
+    }
+    /// Only accepts numbers with at most `places` digits after the decimal point.
+    ///
+    /// Digits are counted in the shortest text that gives back the same number, so `1.50` has
+    /// one decimal place.
+    fn max_decimal_places(self, places: usize) -> Self {
+        self.inside(decimal_places_rule(places))
+    }
+    /// Only accepts numbers with at most `places` digits after the decimal point with custom
+    /// error message.
+    fn max_decimal_places_err(self, places: usize, err: impl ToString) -> Self {
+        self.inside_err(decimal_places_rule(places), err)
+    }
+    /// Only accepts multiples of `step`, such as `0.25` for quarters.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// let hours: f32 = input().msg("Hours worked: ").min_max(0.0, 24.0).step(0.25).get();
+    /// ```
+    fn step(self, step: f64) -> Self {
+        self.inside(step_rule(step))
+    }
+    /// Only accepts multiples of `step` with custom error message.
+    fn step_err(self, step: f64, err: impl ToString) -> Self {
+        self.inside_err(step_rule(step), err)
+    }
+    /// Rejects numbers within `eps` of `this`.
+    ///
+    /// Use this instead of [`InputConstraints::not`](crate::InputConstraints::not), which only
+    /// rejects the exact value.
+    fn approx_not(self, this: f64, eps: f64) -> Self {
+        self.inside(approx_not_rule(this, eps))
+    }
+    /// Rejects numbers within `eps` of `this` with custom error message.
+    fn approx_not_err(self, this: f64, eps: f64, err: impl ToString) -> Self {
+        self.inside_err(approx_not_rule(this, eps), err)
+    }
+}
+
+fn finite_rule<T: Into<f64> + Copy>() -> Rule<T> {
+    Rule::new(|x: &T| (*x).into().is_finite(), Message::Finite)
+}
+
+fn not_nan_rule<T: Into<f64> + Copy>() -> Rule<T> {
+    Rule::new(|x: &T| !(*x).into().is_nan(), Message::NotNan)
+}
+
+fn decimal_places_rule<T: Display>(places: usize) -> Rule<T> {
+    Rule::new(
+        move |x: &T| {
+            let text = x.to_string();
+            text.split_once('.').map_or(0, |(_, digits)| digits.len()) <= places
+        },
+        Message::DecimalPlaces(places),
+    )
+}
+
+fn step_rule<T: Into<f64> + Copy + Display>(step: f64) -> Rule<T> {
+    Rule::new(
+        move |x: &T| {
+            let digits = decimal(&x.to_string()).zip(decimal(&step.to_string()));
+            match digits.and_then(|(x, step)| align(x, step)) {
+                Some((x, step)) => x.checked_rem(step).map_or(x == 0, |rest| rest == 0),
+                None => multiple((*x).into(), step),
+            }
+        },
+        Message::Step(step.to_string()),
+    )
+}
+
+// Splits the text of a number into its digits and the number of digits after the decimal
+// point, so `-1.25` becomes `(-125, 2)`.
+fn decimal(text: &str) -> Option<(i128, u32)> {
+    let (whole, fraction) = text.split_once('.').unwrap_or((text, ""));
+    let digits: i128 = format!("{}{}", whole, fraction).parse().ok()?;
+    // `digits` would not parse with more than 39 digits, so the length fits.
+    Some((digits, fraction.len() as u32))
+}
+
+// Scales two decimals to the same number of digits after the decimal point.
+fn align((x, x_scale): (i128, u32), (y, y_scale): (i128, u32)) -> Option<(i128, i128)> {
+    let scale = |n: i128, by: u32| n.checked_mul(10i128.checked_pow(by)?);
+    Some((
+        scale(x, y_scale.saturating_sub(x_scale))?,
+        scale(y, x_scale.saturating_sub(y_scale))?,
+    ))
+}
+
+// Fallback for numbers too large to compare digit by digit.
+fn multiple(x: f64, step: f64) -> bool {
+    let steps = x / step;
+    (steps - steps.round()).abs() <= steps.abs().max(1.0) * 1e-9
+}
+
+fn approx_not_rule<T: Into<f64> + Copy>(this: f64, eps: f64) -> Rule<T> {
+    Rule::new(
+        move |x: &T| ((*x).into() - this).abs() > eps,
+        Message::ApproxNot(this.to_string(), eps.to_string()),
+    )
+}
+
+impl<T> PathConstraints<T> for InputBuilder<T>
+where
+    T: FromStr + AsRef<Path> + From<PathBuf> + 'static,
+{
+    fn expand(self) -> Self {
+        self.map(on_path(expand))
+    }
+    fn absolute(self) -> Self {
+        self.map(on_path(absolute))
+    }
+}
+
+impl<T> PathConstraints<T> for InputBuilderOnce<T>
+where
+    T: FromStr + AsRef<Path> + From<PathBuf> + 'static,
+{
+    fn expand(self) -> Self {
+        self.internal(PathConstraints::expand)
+    }
+    fn absolute(self) -> Self {
+        self.internal(PathConstraints::absolute)
+    }
+}
+
+impl<T> PathConstraints<T> for ListBuilder<T>
+where
+    T: FromStr + AsRef<Path> + From<PathBuf> + 'static,
+{
+    fn expand(self) -> Self {
+        self.internal(PathConstraints::expand)
+    }
+    fn absolute(self) -> Self {
+        self.internal(PathConstraints::absolute)
+    }
+}
+
+impl<T: FromStr + AsRef<str> + 'static> StringConstraints<T> for InputBuilder<T> {}
+
+impl<T: FromStr + AsRef<str> + 'static> StringConstraints<T> for InputBuilderOnce<T> {}
+
+impl<T: FromStr + AsRef<str> + 'static> StringConstraints<T> for ListBuilder<T> {}
+
+impl<T: FromStr + Into<f64> + Copy + Display + 'static> FloatConstraints<T> for InputBuilder<T> {}
+
+impl<T: FromStr + Into<f64> + Copy + Display + 'static> FloatConstraints<T>
+    for InputBuilderOnce<T>
+{
+}
+
+impl<T: FromStr + Into<f64> + Copy + Display + 'static> FloatConstraints<T> for ListBuilder<T> {}
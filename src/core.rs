@@ -1,70 +1,467 @@
-use crate::{Prompt, Test};
+use crate::{
+    completion::{Choices, Completer},
+    editor::Terminal,
+    error::InputError,
+    messages::{self, Message},
+    suggest::closest,
+    template::render,
+    theme, DefaultValue, InputBuilder,
+};
 use std::{
-    io::{self, Write},
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader, IsTerminal, Write},
     str::FromStr,
     string::ToString,
+    sync::atomic::{AtomicUsize, Ordering},
 };
 
+// Lines read from stdin by builders, used to number lines in batch mode.
+static STDIN_LINES: AtomicUsize = AtomicUsize::new(0);
+
+// Where a builder reads lines from. Clones of a builder share it.
+pub(crate) struct Source {
+    // Reads stdin when `None`.
+    pub reader: Option<Box<dyn BufRead>>,
+    // Lines read from `reader` so far, used to number lines in batch mode. Lines of stdin are
+    // counted in `STDIN_LINES` instead, since every builder reading it shares them.
+    pub lines: usize,
+}
+
+// Opens the controlling terminal for reading and writing.
+fn open_tty() -> io::Result<(BufReader<File>, File)> {
+    let (input, output) = if cfg!(windows) {
+        ("CONIN$", "CONOUT$")
+    } else {
+        ("/dev/tty", "/dev/tty")
+    };
+    let open = |path| {
+        OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(path)
+            .map_err(|e| {
+                io::Error::new(
+                    e.kind(),
+                    format!("no terminal to prompt on, could not open {}: {}", path, e),
+                )
+            })
+    };
+    Ok((BufReader::new(open(input)?), open(output)?))
+}
+
+// Whether input is typed by someone rather than read from a file or pipe.
+pub(crate) fn is_interactive<T: FromStr>(builder: &InputBuilder<T>) -> bool {
+    builder.tty || (builder.source.borrow().reader.is_none() && io::stdin().is_terminal())
+}
+
 // Core function when running `.get()`.
 pub(crate) fn read_input<T: FromStr>(
-    prompt: &Prompt,
-    err: &str,
-    default: Option<T>,
-    tests: &[Test<T>],
-    err_pass: &dyn Fn(&T::Err) -> Option<String>,
-    prompt_output: &mut dyn Write,
+    builder: &InputBuilder<T>,
+    default: Option<DefaultValue<T>>,
+    default_hint: Option<String>,
 ) -> io::Result<T> {
+    read_with(builder, default, default_hint, |input, raw, attempt| {
+        parse_line(input, raw, builder, attempt)
+    })
+}
+
+// Prompts with the settings of `builder` until `parse` accepts a line.
+// Lines that are not valid UTF-8 are given to `parse` decoded lossily along with their bytes.
+pub(crate) fn read_with<T: FromStr, V>(
+    builder: &InputBuilder<T>,
+    default: Option<DefaultValue<V>>,
+    default_hint: Option<String>,
+    parse: impl Fn(&str, Option<&[u8]>, usize) -> Result<V, Rejection>,
+) -> io::Result<V> {
+    read_with_control(builder, default, default_hint, |_| None, parse)
+}
+
+// Works like `read_with`, but returns what `control` gives for a line straight away. Such lines,
+// like the word that ends collecting values, are not added to history or echoed. `control` is
+// also given the empty line read at the end of input.
+pub(crate) fn read_with_control<T: FromStr, V>(
+    builder: &InputBuilder<T>,
+    default: Option<DefaultValue<V>>,
+    default_hint: Option<String>,
+    control: impl Fn(&str) -> Option<V>,
+    parse: impl Fn(&str, Option<&[u8]>, usize) -> Result<V, Rejection>,
+) -> io::Result<V> {
     fn try_flush(prompt_output: &mut dyn Write) {
         prompt_output.flush().unwrap_or(())
     }
 
-    fn input_as_string() -> io::Result<String> {
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        Ok(input)
+    fn read_bytes(reader: &mut dyn BufRead, bytes: &mut Vec<u8>) -> io::Result<()> {
+        bytes.clear();
+        reader.read_until(b'\n', bytes)?;
+        Ok(())
     }
 
-    let _ = write!(prompt_output, "{}", prompt.msg);
+    let batch = builder.batch.unwrap_or_else(|| !is_interactive(builder));
+    let mut tty = if builder.tty { Some(open_tty()?) } else { None };
+    let mut source = builder.source.borrow_mut();
+    let Source {
+        reader: source_reader,
+        lines,
+    } = &mut *source;
+    let from_stdin = source_reader.is_none();
+    let mut stdin;
+    let mut prompt_borrow;
+    let mut terminal = None;
+    let (reader, prompt_output, prompt_terminal): (&mut dyn BufRead, &mut dyn Write, bool) =
+        match &mut tty {
+            Some((input, output)) => {
+                terminal = Terminal::of(input.get_ref());
+                (input, output, true)
+            }
+            None => {
+                prompt_borrow = builder.prompt_output.borrow_mut();
+                let reader: &mut dyn BufRead = match source_reader {
+                    Some(reader) => reader,
+                    None => {
+                        terminal = Terminal::stdin();
+                        stdin = io::stdin().lock();
+                        &mut stdin
+                    }
+                };
+                (reader, &mut **prompt_borrow, builder.prompt_terminal)
+            }
+        };
+    // Keys are only handled one by one when there is something to complete.
+    let completing = !builder.completers.is_empty()
+        || builder.tests.iter().any(|test| test.candidates.is_some());
+    let editor = terminal.filter(|_| prompt_terminal && completing);
+
+    let prompt = &builder.msg;
+    let theme = builder.theme.clone().unwrap_or_else(theme::default);
+    let colour = theme.use_colour(prompt_terminal);
+    // Initial text is what an empty line submits, so it is shown in place of the default.
+    let hint = match &builder.initial_text {
+        Some(text) => Some(format!("[{}]", text)),
+        None => default_hint,
+    };
+    let prompt_msg = theme.paint_prompt(&prompt.msg, hint.as_deref(), colour);
+    let mut err_output = builder.err_output.as_ref().map(|x| x.borrow_mut());
+    let err_colour = match err_output {
+        Some(_) => theme.use_colour(builder.err_terminal),
+        None => colour,
+    };
+
+    if let Some(history) = &builder.history {
+        for line in history.recent() {
+            let _ = writeln!(prompt_output, "{}", theme.paint_hint(&line, colour));
+        }
+    }
+    let _ = write!(prompt_output, "{}", prompt_msg);
     try_flush(prompt_output);
 
+    let mut suggestion: Option<String> = None;
+    let mut attempt = 0;
+    let mut bytes = Vec::new();
+
     loop {
-        let input = input_as_string()?;
+        match &editor {
+            Some(editor) => editor.read_line(
+                reader,
+                prompt_output,
+                &prompt_msg,
+                &mut bytes,
+                |partial| completions(builder, partial),
+                |list| theme.paint_hint(list, colour),
+            )?,
+            None => read_bytes(reader, &mut bytes)?,
+        }
+        let (mut input, mut raw) = match std::str::from_utf8(&bytes) {
+            Ok(input) => (input.to_string(), None),
+            Err(_) => (
+                String::from_utf8_lossy(&bytes).into_owned(),
+                Some(&bytes[..]),
+            ),
+        };
+        let line = match (builder.tty, input.is_empty()) {
+            (false, false) if from_stdin => STDIN_LINES.fetch_add(1, Ordering::Relaxed) + 1,
+            (false, false) => {
+                *lines += 1;
+                *lines
+            }
+            _ => 0,
+        };
+
+        if let Some(v) = control(&input) {
+            return Ok(v);
+        }
+
+        // `y` only takes the suggestion when it is not a valid value itself.
+        if let Some(suggested) = suggestion.take() {
+            if input.trim().eq_ignore_ascii_case("y") && parse(&input, None, attempt + 1).is_err() {
+                input = suggested;
+                raw = None;
+            }
+        }
+
+        if let Some(history) = &builder.history {
+            if let Some(entry) = history.recall(&input) {
+                let _ = writeln!(prompt_output, "{}", theme.paint_hint(&entry, colour));
+                input = entry;
+                raw = None;
+            }
+        }
+
+        // Where keys are not handled one by one, a line typed as a prefix followed by Tab and
+        // then Enter asks for completions.
+        if let Some(partial) = input.trim_end_matches(&['\n', '\r'][..]).strip_suffix('\t') {
+            let matches = completions(builder, partial.trim_start());
+            if let [only] = &matches[..] {
+                let _ = writeln!(prompt_output, "{}", theme.paint_hint(only, colour));
+                input = only.clone();
+                raw = None;
+            } else {
+                if !matches.is_empty() {
+                    let hint = theme.paint_hint(&matches.join("  "), colour);
+                    let _ = writeln!(prompt_output, "{}", hint);
+                }
+                let _ = write!(prompt_output, "{}", prompt_msg);
+                try_flush(prompt_output);
+                continue;
+            }
+        }
 
         if input.trim().is_empty() {
-            if let Some(x) = default {
-                return Ok(x);
+            if let Some(text) = &builder.initial_text {
+                input = text.clone();
+            } else if let Some(x) = default {
+                return Ok(x.value());
             }
         };
 
-        match parse_input(input, err, tests, err_pass) {
-            Ok(v) => return Ok(v),
+        // An empty read is the end of input, where a default value is still used.
+        if batch && input.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "input ended before a valid value was read",
+            ));
+        }
+
+        attempt += 1;
+        let parsed = match raw {
+            Some(_) if !builder.lossy && builder.from_bytes.is_none() => Err(Rejection {
+                msg: translated(builder, &Message::InvalidUtf8),
+                suggestion: None,
+            }),
+            Some(_) if builder.lossy => parse(&input, None, attempt),
+            raw => parse(&input, raw, attempt),
+        };
+        match parsed {
+            Ok(v) => {
+                if let Some(history) = &builder.history {
+                    let _ = history.push(&input);
+                }
+                if let Some(echo) = theme.paint_success(input.trim(), colour) {
+                    let _ = writeln!(prompt_output, "{}", echo);
+                }
+                return Ok(v);
+            }
+            Err(e) if batch => {
+                let error = InputError::Invalid {
+                    line,
+                    input: input.trim_end_matches(['\n', '\r']).to_string(),
+                    msg: e.msg,
+                };
+                let _ = match err_output.as_mut() {
+                    Some(err_output) => writeln!(err_output, "{}", error.report()),
+                    None => writeln!(io::stderr(), "{}", error.report()),
+                };
+                return Err(io::Error::new(io::ErrorKind::InvalidData, error));
+            }
             Err(e) => {
-                let _ = writeln!(prompt_output, "{}", e);
+                if builder.max_attempts.is_some_and(|max| attempt >= max) {
+                    return Err(io::Error::new(io::ErrorKind::InvalidInput, e.msg));
+                }
+                let err = theme.paint_error(&e.msg, err_colour);
+                let _ = match err_output.as_mut() {
+                    Some(err_output) => writeln!(err_output, "{}", err),
+                    None => writeln!(prompt_output, "{}", err),
+                };
+                suggestion = e.suggestion;
             }
         };
 
         if prompt.repeat {
-            let _ = write!(prompt_output, "{}", prompt.msg);
+            let _ = write!(prompt_output, "{}", prompt_msg);
             try_flush(prompt_output)
         };
     }
 }
 
+// Collects completions from completers and `select` constraints.
+pub(crate) fn completions<T: FromStr>(builder: &InputBuilder<T>, partial: &str) -> Vec<String> {
+    let choices = builder
+        .tests
+        .iter()
+        .filter_map(|test| test.candidates.clone())
+        .map(|candidates| Choices(candidates).complete(partial));
+    let mut matches = Vec::new();
+    for completion in builder
+        .completers
+        .iter()
+        .map(|completer| completer.complete(partial))
+        .chain(choices)
+        .flatten()
+    {
+        if !matches.contains(&completion) {
+            matches.push(completion);
+        }
+    }
+    matches
+}
+
+// Reason input was rejected.
+#[derive(Debug)]
+pub(crate) struct Rejection {
+    pub msg: String,
+    pub suggestion: Option<String>,
+}
+
+// Parses a line, building the value from its bytes if it is not valid UTF-8 and the builder
+// can do that.
+pub(crate) fn parse_line<T: FromStr>(
+    input: &str,
+    raw: Option<&[u8]>,
+    builder: &InputBuilder<T>,
+    attempt: usize,
+) -> Result<T, Rejection> {
+    match (raw, builder.from_bytes) {
+        (Some(raw), Some(from_bytes)) => {
+            check(from_bytes(raw.trim_ascii()), input.trim(), builder, attempt)
+        }
+        _ => parse_input(input, builder, attempt),
+    }
+}
+
 pub(crate) fn parse_input<T: FromStr>(
-    input: String,
-    err: &str,
-    tests: &[Test<T>],
-    err_pass: &dyn Fn(&T::Err) -> Option<String>,
-) -> Result<T, String> {
-    match T::from_str(input.trim()) {
-        Ok(value) => {
-            for test in tests {
-                if !(test.func)(&value) {
-                    return Err(test.err.clone().unwrap_or_else(|| err.to_string()));
-                }
-            }
-            Ok(value)
+    input: &str,
+    builder: &InputBuilder<T>,
+    attempt: usize,
+) -> Result<T, Rejection> {
+    let input = input.trim();
+    match T::from_str(input) {
+        Ok(value) => check(value, input, builder, attempt),
+        Err(error) => Err(Rejection {
+            msg: parse_error(&error, input, builder, attempt)
+                .unwrap_or_else(|| translated(builder, &Message::Invalid)),
+            suggestion: None,
+        }),
+    }
+}
+
+// Custom error message for input that can not be parsed, if `builder` has one.
+pub(crate) fn parse_error<T: FromStr>(
+    error: &T::Err,
+    input: &str,
+    builder: &InputBuilder<T>,
+    attempt: usize,
+) -> Option<String> {
+    (builder.err_match)(error).or_else(|| {
+        builder
+            .err
+            .as_ref()
+            .map(|err| fill(err, input, None, None, builder, attempt))
+    })
+}
+
+// Runs the tests of `builder` on a value parsed from `input`.
+pub(crate) fn check<T: FromStr>(
+    value: T,
+    input: &str,
+    builder: &InputBuilder<T>,
+    attempt: usize,
+) -> Result<T, Rejection> {
+    let value = builder.maps.iter().fold(value, |value, map| map(value));
+    for test in &builder.tests {
+        if !(test.func)(&value) {
+            let message = test.message.as_ref();
+            let custom = test
+                .err
+                .as_ref()
+                .or(builder.err.as_ref())
+                .map(|err| fill(err, input, Some(&value), message, builder, attempt));
+            let suggestion = test
+                .candidates
+                .as_ref()
+                .and_then(|candidates| closest(input, candidates))
+                .filter(|suggestion| *suggestion != input);
+            return Err(match (suggestion, custom) {
+                (Some(suggestion), None) => Rejection {
+                    msg: translated(
+                        builder,
+                        &Message::DidYouMean(input.to_string(), suggestion.clone()),
+                    ),
+                    suggestion: Some(suggestion.clone()),
+                },
+                (Some(suggestion), Some(msg)) => Rejection {
+                    msg: format!(
+                        "{} {}",
+                        msg,
+                        translated(builder, &Message::Suggest(suggestion.clone()))
+                    ),
+                    suggestion: Some(suggestion.clone()),
+                },
+                (None, custom) => Rejection {
+                    msg: custom
+                        .or_else(|| test.message.as_ref().map(|m| translated(builder, m)))
+                        .unwrap_or_else(|| translated(builder, &Message::Invalid)),
+                    suggestion: None,
+                },
+            });
         }
-        Err(error) => Err(err_pass(&error).unwrap_or_else(|| err.to_string())),
     }
+    Ok(value)
+}
+
+// Fills the placeholders of a custom error message. `{min}` and `{max}` are only taken from the
+// message of the failing test and are left empty when it has no bounds.
+fn fill<T: FromStr>(
+    template: &str,
+    input: &str,
+    value: Option<&T>,
+    message: Option<&Message>,
+    builder: &InputBuilder<T>,
+    attempt: usize,
+) -> String {
+    let (min, max) = message.map_or((None, None), Message::bounds);
+    render(template, |name| match name {
+        "value" => Some(match (value, builder.display) {
+            (Some(value), Some(display)) => display(value),
+            _ => input.to_string(),
+        }),
+        "min" => Some(min.map(ToString::to_string).unwrap_or_default()),
+        "max" => Some(max.map(ToString::to_string).unwrap_or_default()),
+        _ => placeholder(name, input, builder, attempt),
+    })
+}
+
+// Placeholders every custom error message can use.
+pub(crate) fn placeholder<T: FromStr>(
+    name: &str,
+    input: &str,
+    builder: &InputBuilder<T>,
+    attempt: usize,
+) -> Option<String> {
+    match name {
+        "input" => Some(input.to_string()),
+        "attempt" => Some(attempt.to_string()),
+        "remaining" => Some(builder.max_attempts.map_or_else(
+            || "unlimited".to_string(),
+            |max| max.saturating_sub(attempt).to_string(),
+        )),
+        _ => None,
+    }
+}
+
+// Text of a built-in message in the language chosen for the builder.
+pub(crate) fn translated<T: FromStr>(builder: &InputBuilder<T>, message: &Message) -> String {
+    builder
+        .translate
+        .as_ref()
+        .and_then(|translate| translate.translate(message))
+        .unwrap_or_else(|| messages::text(message))
 }
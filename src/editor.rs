@@ -0,0 +1,212 @@
+// Line editing with Tab completion on terminals.
+//
+// Terminals in their normal line mode only hand over a line once Enter is pressed, so while a
+// line with completions is typed the terminal is switched to raw mode and keys are handled here.
+// Only Unix terminals are supported. Elsewhere a line ending with a tab is completed instead.
+
+use std::{
+    fs::File,
+    io::{self, BufRead, Write},
+};
+
+// A terminal that input is typed on.
+pub(crate) struct Terminal {
+    #[cfg(unix)]
+    fd: std::os::unix::io::RawFd,
+}
+
+impl Terminal {
+    // Stdin, if it is a terminal that can be switched to raw mode.
+    pub(crate) fn stdin() -> Option<Self> {
+        #[cfg(unix)]
+        return std::io::IsTerminal::is_terminal(&io::stdin()).then_some(Self {
+            fd: libc::STDIN_FILENO,
+        });
+        #[cfg(not(unix))]
+        return None;
+    }
+    // A terminal opened by `use_tty`.
+    #[cfg_attr(not(unix), allow(unused_variables))]
+    pub(crate) fn of(file: &File) -> Option<Self> {
+        #[cfg(unix)]
+        return Some(Self {
+            fd: std::os::unix::io::AsRawFd::as_raw_fd(file),
+        });
+        #[cfg(not(unix))]
+        return None;
+    }
+    // Reads a line into `line` in raw mode. See `edit`.
+    #[cfg_attr(not(unix), allow(unused_variables))]
+    pub(crate) fn read_line(
+        &self,
+        reader: &mut dyn BufRead,
+        output: &mut dyn Write,
+        prompt: &str,
+        line: &mut Vec<u8>,
+        complete: impl Fn(&str) -> Vec<String>,
+        paint: impl Fn(&str) -> String,
+    ) -> io::Result<()> {
+        #[cfg(unix)]
+        {
+            let raw = RawMode::enable(self.fd)?;
+            let result = edit(reader, output, prompt, line, complete, paint);
+            drop(raw);
+            // Ctrl-C is read as a key in raw mode, so the signal is sent once the terminal is
+            // back in line mode.
+            if matches!(&result, Err(e) if e.kind() == io::ErrorKind::Interrupted) {
+                // SAFETY: `raise` has no preconditions.
+                unsafe { libc::raise(libc::SIGINT) };
+            }
+            result
+        }
+        #[cfg(not(unix))]
+        unreachable!("terminals are only edited on unix")
+    }
+}
+
+// Terminal settings that are put back when dropped.
+#[cfg(unix)]
+struct RawMode {
+    fd: std::os::unix::io::RawFd,
+    saved: libc::termios,
+}
+
+#[cfg(unix)]
+impl RawMode {
+    // Stops the terminal from echoing keys, handling Ctrl-C and waiting for Enter.
+    fn enable(fd: std::os::unix::io::RawFd) -> io::Result<Self> {
+        // SAFETY: `termios` is plain data that `tcgetattr` fills in.
+        let mut saved: libc::termios = unsafe { std::mem::zeroed() };
+        // SAFETY: `saved` is a valid `termios` to write to.
+        if unsafe { libc::tcgetattr(fd, &mut saved) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let mut raw = saved;
+        raw.c_lflag &= !(libc::ICANON | libc::ECHO | libc::ISIG);
+        raw.c_cc[libc::VMIN] = 1;
+        raw.c_cc[libc::VTIME] = 0;
+        // SAFETY: `raw` is a valid `termios` read from the same terminal.
+        if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &raw) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self { fd, saved })
+    }
+}
+
+#[cfg(unix)]
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        // SAFETY: `saved` was read from this terminal by `tcgetattr`.
+        unsafe { libc::tcsetattr(self.fd, libc::TCSANOW, &self.saved) };
+    }
+}
+
+// Next byte typed, or `None` at the end of input.
+fn next_byte(reader: &mut dyn BufRead) -> io::Result<Option<u8>> {
+    let byte = loop {
+        match reader.fill_buf() {
+            Ok(buf) => break buf.first().copied(),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    };
+    if byte.is_some() {
+        reader.consume(1);
+    }
+    Ok(byte)
+}
+
+// Longest text that every match starts with.
+fn common_prefix(matches: &[String]) -> Option<&str> {
+    let (first, rest) = matches.split_first()?;
+    let mut prefix = first.as_str();
+    for other in rest {
+        let len = prefix
+            .char_indices()
+            .zip(other.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or_else(|| prefix.len().min(other.len()), |((i, _), _)| i);
+        prefix = &prefix[..len];
+    }
+    Some(prefix)
+}
+
+// Handles the keys typed for one line, echoing them to `output`. The line ends with a newline
+// when Enter is pressed and is left empty when input ends or Ctrl-D is pressed on an empty line.
+//
+// Tab completes the text typed so far with `complete` as far as all matches agree. Pressing it
+// again lists the matches, styled with `paint`. Ctrl-C gives an `Interrupted` error.
+#[cfg_attr(not(unix), allow(dead_code))]
+pub(crate) fn edit(
+    reader: &mut dyn BufRead,
+    output: &mut dyn Write,
+    prompt: &str,
+    line: &mut Vec<u8>,
+    complete: impl Fn(&str) -> Vec<String>,
+    paint: impl Fn(&str) -> String,
+) -> io::Result<()> {
+    // Only the last line of a prompt is written again when the typed text changes.
+    let prompt = prompt.rsplit('\n').next().unwrap_or_default();
+    line.clear();
+    while let Some(byte) = next_byte(reader)? {
+        match byte {
+            b'\r' | b'\n' => {
+                line.push(b'\n');
+                writeln!(output)?;
+                break;
+            }
+            3 => return Err(io::Error::new(io::ErrorKind::Interrupted, "interrupted")),
+            4 if line.is_empty() => break,
+            b'\t' => {
+                let typed = String::from_utf8_lossy(line).into_owned();
+                let typed = typed.trim_start();
+                let matches = complete(typed);
+                match common_prefix(&matches) {
+                    Some(prefix) if prefix.len() > typed.len() => {
+                        *line = prefix.as_bytes().to_vec();
+                        write!(output, "\r{}{}\x1b[K", prompt, prefix)?;
+                    }
+                    _ if matches.len() > 1 => {
+                        let typed = String::from_utf8_lossy(line);
+                        write!(
+                            output,
+                            "\n{}\n{}{}",
+                            paint(&matches.join("  ")),
+                            prompt,
+                            typed
+                        )?;
+                    }
+                    _ => output.write_all(b"\x07")?,
+                }
+            }
+            // Backspace removes the last character, which can be several bytes.
+            0x7f | 8 => {
+                if !line.is_empty() {
+                    while let Some(byte) = line.pop() {
+                        if byte & 0xc0 != 0x80 {
+                            break;
+                        }
+                    }
+                    output.write_all(b"\x08 \x08")?;
+                }
+            }
+            // Keys such as arrows send escape sequences, which are ignored.
+            0x1b => {
+                if let Some(b'[' | b'O') = next_byte(reader)? {
+                    while let Some(byte) = next_byte(reader)? {
+                        if (0x40..=0x7e).contains(&byte) {
+                            break;
+                        }
+                    }
+                }
+            }
+            0..=0x1f => {}
+            _ => {
+                line.push(byte);
+                output.write_all(&[byte])?;
+            }
+        }
+        output.flush()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,86 @@
+//! Errors returned when input is read without prompting.
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io,
+};
+
+/// Error returned when reading lines from a file or pipe.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum InputError {
+    /// Reading failed.
+    Io(io::Error),
+    /// A line did not pass parsing or one of the tests.
+    Invalid {
+        /// Number of the line, counting from 1.
+        line: usize,
+        /// Text of the line.
+        input: String,
+        /// Error message, as it would be written when prompting.
+        msg: String,
+    },
+}
+
+impl InputError {
+    /// Formats the error like a compiler diagnostic, with the line under the message.
+    ///
+    /// ```text
+    /// error: Must be at least 18.
+    ///  --> line 3
+    ///   |
+    /// 3 | 17
+    ///   |
+    /// ```
+    pub fn report(&self) -> String {
+        match self {
+            Self::Io(e) => format!("error: {}", e),
+            Self::Invalid { line, input, msg } => {
+                let gutter = " ".repeat(line.to_string().len());
+                format!(
+                    "error: {msg}\n{gutter}--> line {line}\n{gutter} |\n{line} | {input}\n{gutter} |",
+                    msg = msg,
+                    gutter = gutter,
+                    line = line,
+                    input = input
+                )
+            }
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::Invalid { line, input, msg } => {
+                write!(f, "line {} (`{}`): {}", line, input, msg)
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Invalid { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for InputError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<InputError> for io::Error {
+    fn from(error: InputError) -> Self {
+        match error {
+            InputError::Io(e) => e,
+            invalid => io::Error::new(io::ErrorKind::InvalidData, invalid),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Persistent per-prompt history of accepted answers.
+//!
+//! A [`History`] is attached to a builder with [`InputBuild::history`](crate::InputBuild::history).
+//! Every value that passes all tests is appended to a file in the user's data directory and can
+//! be recalled the next time the same prompt is shown.
+
+use std::{env, fs, io, path::PathBuf};
+
+const DEFAULT_LIMIT: usize = 500;
+
+/// Settings for a persistent history file.
+///
+/// Entries can be recalled by pressing the up arrow before enter (once for the most recent entry,
+/// twice for the one before and so on) or, when [`History::show_recent`] is used, by typing `!`
+/// followed by the number shown in the list of recent values.
+///
+/// ```no_run
+/// # use read_input::prelude::*;
+/// use read_input::history::History;
+/// let host: String = input()
+///     .msg("Host: ")
+///     .history(History::new("hosts").limit(50).show_recent(5))
+///     .get();
+/// ```
+#[derive(Clone, Debug)]
+pub struct History {
+    path: Option<PathBuf>,
+    limit: usize,
+    dedup: bool,
+    recent: usize,
+}
+
+impl History {
+    /// Creates a history stored under `name` in the user's data directory.
+    ///
+    /// Histories with the same name are shared between prompts and between runs of the program.
+    pub fn new(name: impl ToString) -> Self {
+        let name: String = name
+            .to_string()
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        Self::at_path(data_dir().map(|dir| dir.join("read_input").join("history").join(name)))
+    }
+    /// Creates a history stored in a specific file.
+    pub fn at(path: impl Into<PathBuf>) -> Self {
+        Self::at_path(Some(path.into()))
+    }
+    fn at_path(path: Option<PathBuf>) -> Self {
+        Self {
+            path,
+            limit: DEFAULT_LIMIT,
+            dedup: true,
+            recent: 0,
+        }
+    }
+    /// Sets the maximum number of entries kept. The oldest entries are dropped first.
+    ///
+    /// The default limit is 500 entries.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+    /// Sets whether an older copy of an entry is removed when the same value is accepted again.
+    ///
+    /// Deduplication is on by default.
+    pub fn dedup(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+        self
+    }
+    /// Prints a numbered list of up to `count` recent values before the prompt.
+    pub fn show_recent(mut self, count: usize) -> Self {
+        self.recent = count;
+        self
+    }
+    /// Location of the history file.
+    ///
+    /// Returns `None` if the user's data directory could not be found.
+    pub fn path(&self) -> Option<&PathBuf> {
+        self.path.as_ref()
+    }
+    /// Stored entries, oldest first.
+    pub fn entries(&self) -> Vec<String> {
+        self.path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|s| s.lines().map(ToString::to_string).collect())
+            .unwrap_or_default()
+    }
+    // Lines of the numbered list of recent values.
+    pub(crate) fn recent(&self) -> Vec<String> {
+        self.entries()
+            .iter()
+            .rev()
+            .take(self.recent)
+            .enumerate()
+            .map(|(i, entry)| format!("  {}) {}", i + 1, entry))
+            .collect()
+    }
+    // Replaces up arrow key presses or `!n` with the matching entry.
+    pub(crate) fn recall(&self, input: &str) -> Option<String> {
+        let input = input.trim();
+        let back = if input.starts_with('\u{1b}') {
+            let presses = input.matches("\u{1b}[A").count() + input.matches("\u{1b}OA").count();
+            if presses * 3 != input.len() {
+                return None;
+            }
+            presses
+        } else if let Some(number) = input.strip_prefix('!') {
+            match number.parse::<usize>() {
+                Ok(n) if n <= self.recent => n,
+                _ => return None,
+            }
+        } else {
+            return None;
+        };
+        let entries = self.entries();
+        back.checked_sub(1)
+            .and_then(|i| entries.iter().rev().nth(i))
+            .cloned()
+    }
+    // Appends an accepted value to the history file.
+    pub(crate) fn push(&self, entry: &str) -> io::Result<()> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        let entry = entry.trim();
+        if entry.is_empty() || entry.contains('\n') {
+            return Ok(());
+        }
+        let mut entries = self.entries();
+        if self.dedup {
+            entries.retain(|x| x != entry);
+        }
+        entries.push(entry.to_string());
+        let skip = entries.len().saturating_sub(self.limit);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut contents = String::new();
+        for entry in &entries[skip..] {
+            contents.push_str(entry);
+            contents.push('\n');
+        }
+        fs::write(path, contents)
+    }
+}
+
+impl From<&str> for History {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<String> for History {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+// Platform specific directory for application data.
+fn data_dir() -> Option<PathBuf> {
+    let var = |name| {
+        env::var_os(name)
+            .filter(|x| !x.is_empty())
+            .map(PathBuf::from)
+    };
+    if cfg!(windows) {
+        var("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        var("HOME").map(|home| home.join("Library").join("Application Support"))
+    } else {
+        var("XDG_DATA_HOME")
+            .filter(|dir| dir.is_absolute())
+            .or_else(|| var("HOME").map(|home| home.join(".local").join("share")))
+    }
+}
@@ -0,0 +1,156 @@
+//! Reading validated values from every line of a file or pipe.
+//!
+//! [`InputBuilder::iter`] reads stdin line after line until it ends, checking each line with the
+//! tests of the builder. No prompts are written.
+//!
+//! ```no_run
+//! # use read_input::prelude::*;
+//! use read_input::iter::OnInvalid;
+//! let total: u64 = input::<u64>()
+//!     .max(1000)
+//!     .iter()
+//!     .on_invalid(OnInvalid::Skip)
+//!     .map(Result::unwrap)
+//!     .sum();
+//! ```
+
+use crate::{
+    core::{parse_line, translated, Rejection},
+    error::InputError,
+    messages::Message,
+    InputBuilder,
+};
+use std::{
+    io::{self, BufRead, StdinLock},
+    str::FromStr,
+};
+
+/// What an [`InputIter`] does with lines that are not valid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OnInvalid {
+    /// Leaves out invalid lines.
+    Skip,
+    /// Yields an error for the first invalid line and then ends.
+    Stop,
+    /// Yields an error for each invalid line and carries on. This is the default.
+    Yield,
+}
+
+/// Iterator over the values of each line, created by [`InputBuilder::iter`].
+pub struct InputIter<'a, T: FromStr, R> {
+    builder: &'a InputBuilder<T>,
+    reader: R,
+    line: Vec<u8>,
+    line_number: usize,
+    on_invalid: OnInvalid,
+    done: bool,
+}
+
+impl<'a, T: FromStr, R: BufRead> InputIter<'a, T, R> {
+    /// Sets what is done with lines that are not valid.
+    pub fn on_invalid(mut self, on_invalid: OnInvalid) -> Self {
+        self.on_invalid = on_invalid;
+        self
+    }
+}
+
+impl<'a, T: FromStr, R: BufRead> Iterator for InputIter<'a, T, R> {
+    type Item = Result<T, InputError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.line.clear();
+            match self.reader.read_until(b'\n', &mut self.line) {
+                Ok(0) => self.done = true,
+                Ok(_) => {
+                    self.line_number += 1;
+                    let (input, parsed) = parse_bytes(&self.line, self.builder);
+                    let e = match parsed {
+                        Ok(value) => return Some(Ok(value)),
+                        Err(e) => e,
+                    };
+                    let error = InputError::Invalid {
+                        line: self.line_number,
+                        input,
+                        msg: e.msg,
+                    };
+                    match self.on_invalid {
+                        OnInvalid::Skip => {}
+                        OnInvalid::Stop => {
+                            self.done = true;
+                            return Some(Err(error));
+                        }
+                        OnInvalid::Yield => return Some(Err(error)),
+                    }
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(InputError::Io(e)));
+                }
+            }
+        }
+        None
+    }
+}
+
+// Parses a line read as bytes like typed input, giving its text along with the result. Lines
+// that are not valid UTF-8 are only accepted with `lossy_utf8` or a builder that parses bytes.
+fn parse_bytes<T: FromStr>(
+    line: &[u8],
+    builder: &InputBuilder<T>,
+) -> (String, Result<T, Rejection>) {
+    let mut line = line;
+    while let [rest @ .., b'\n' | b'\r'] = line {
+        line = rest;
+    }
+    match std::str::from_utf8(line) {
+        Ok(input) => (input.to_string(), parse_line(input, None, builder, 1)),
+        Err(_) => {
+            let input = String::from_utf8_lossy(line).into_owned();
+            let parsed = if builder.lossy {
+                parse_line(&input, None, builder, 1)
+            } else if builder.from_bytes.is_some() {
+                parse_line(&input, Some(line), builder, 1)
+            } else {
+                Err(Rejection {
+                    msg: translated(builder, &Message::InvalidUtf8),
+                    suggestion: None,
+                })
+            };
+            (input, parsed)
+        }
+    }
+}
+
+impl<T: FromStr> InputBuilder<T> {
+    /// Reads stdin line after line until it ends and yields the value of each line.
+    ///
+    /// Lines are checked like input typed at a prompt, so a line that is not valid UTF-8 is
+    /// invalid unless [`lossy_utf8`](crate::InputBuild::lossy_utf8) is used or the builder reads
+    /// bytes, like [`input_os_string`](crate::shortcut::input_os_string). Invalid lines are
+    /// yielded as errors unless [`InputIter::on_invalid`] is used. Stdin stays locked until the
+    /// iterator is dropped.
+    pub fn iter(&self) -> InputIter<'_, T, StdinLock<'static>> {
+        self.iter_from(io::stdin().lock())
+    }
+    /// Works like [`InputBuilder::iter`] but reads from `reader`.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// use std::{fs::File, io::BufReader};
+    /// let file = BufReader::new(File::open("ports.txt").unwrap());
+    /// for port in input::<u16>().min(1024).iter_from(file) {
+    ///     println!("{}", port.unwrap());
+    /// }
+    /// ```
+    pub fn iter_from<R: BufRead>(&self, reader: R) -> InputIter<'_, T, R> {
+        InputIter {
+            builder: self,
+            reader,
+            line: Vec::new(),
+            line_number: 0,
+            on_invalid: OnInvalid::Yield,
+            done: false,
+        }
+    }
+}
@@ -64,22 +64,45 @@
 // `impl ToString` is better than `&impl ToString`. Clippy is not ready for impl trait.
 #![allow(clippy::needless_pass_by_value)]
 
+pub mod collect;
+pub mod completion;
+pub mod constraints;
 mod core;
+mod editor;
+pub mod error;
+pub mod history;
+pub mod iter;
+pub mod list;
+mod locales;
+pub mod messages;
 pub mod prelude;
+pub mod scanner;
 pub mod shortcut;
+mod suggest;
+mod template;
 mod test_generators;
 #[cfg(test)]
 mod tests;
+pub mod theme;
+pub mod tuple;
 
-use crate::{core::read_input, test_generators::InsideFunc};
+use crate::{
+    completion::Completer,
+    core::{is_interactive, parse_input, read_input, Source},
+    history::History,
+    messages::{Message, Translate},
+    test_generators::{InsideFunc, NotEqual, Selection},
+    theme::Theme,
+};
 use std::cell::RefCell;
-use std::io::Write;
-use std::{cmp::PartialOrd, io, rc::Rc, str::FromStr, string::ToString};
+use std::io::{BufRead, IsTerminal, Write};
+use std::{
+    cmp::PartialOrd, env, ffi::OsString, fmt::Display, io, rc::Rc, str::FromStr, string::ToString,
+};
 
-const DEFAULT_ERR: &str = "That value does not pass. Please try again";
-
-/// Trait implemented by [InputBuilder] and [InputBuilderOnce] to standardize input settings.
-pub trait InputBuild<T: FromStr> {
+/// Trait implemented by [InputBuilder], [InputBuilderOnce], [`ListBuilder`](list::ListBuilder) and
+/// [`TupleBuilder`](tuple::TupleBuilder) to standardize input settings.
+pub trait InputBuild<T> {
     /// Changes or adds a prompt message that gets printed once when input if fetched.
     ///
     /// Custom messages are written on the same line as the input cursor.
@@ -105,7 +128,30 @@ pub trait InputBuild<T: FromStr> {
     fn repeat_msg(self, msg: impl ToString) -> Self;
     /// Changes fallback error message.
     ///
-    /// The default error message is "That value does not pass. Please try again".
+    /// The fallback error message is used when input can not be parsed or fails a test that has
+    /// no error message of its own. Without it constraints such as [`InputConstraints::min`]
+    /// describe themselves ("Must be at least 4.") and anything else prints
+    /// "That value does not pass. Please try again".
+    ///
+    /// Custom error messages given here, to [`InputBuild::add_err_test`] or to
+    /// [`InputBuild::inside_err`] can contain placeholders that are filled in when they are shown.
+    ///
+    /// - `{input}` the line that was typed, without surrounding whitespace.
+    /// - `{value}` the parsed value, if a constraint knows how to display it. Otherwise the same as `{input}`.
+    /// - `{min}` and `{max}` the bounds of the failing constraint. They are empty when the input
+    ///   can not be parsed or the failing test has no bounds.
+    /// - `{attempt}` the number of the current attempt, starting at 1.
+    /// - `{remaining}` attempts left when [`InputBuild::max_attempts`] is used.
+    ///
+    /// Use `{{` and `}}` for literal braces.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// let input = input::<u32>()
+    ///     .inside_err(4..=9, "'{input}' is outside {min}..={max} ({remaining} tries left)")
+    ///     .max_attempts(3)
+    ///     .get();
+    /// ```
     ///
     /// ```no_run
     /// # use read_input::prelude::*;
@@ -200,6 +246,7 @@ pub trait InputBuild<T: FromStr> {
     /// ```
     fn err_match<F>(self, err_match: F) -> Self
     where
+        T: FromStr,
         F: Fn(&T::Err) -> Option<String> + 'static;
     /// Ensures that input is within a range, array or vector.
     ///
@@ -220,18 +267,171 @@ pub trait InputBuild<T: FromStr> {
     /// Does the same thing as [`InputBuild::inside`], but with a custom error message
     /// printed when input fails.
     fn inside_err<U: InsideFunc<T>>(self, constraint: U, err: impl ToString) -> Self;
+    /// Ensures that input is one of `values`.
+    ///
+    /// Unlike [`InputBuild::inside`] with an array or vector, the values are listed in the error
+    /// message, offered as completions and suggested when input is close to one of them.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// let env: String = input().select(["staging", "production"].map(String::from)).get();
+    /// ```
+    fn select<U: IntoIterator<Item = T>>(self, values: U) -> Self
+    where
+        T: PartialEq + Display + 'static,
+        Self: Sized,
+    {
+        self.inside(Selection(values.into_iter().collect()))
+    }
+    /// Does the same thing as [`InputBuild::select`], but with a custom error message
+    /// printed when input fails.
+    fn select_err<U: IntoIterator<Item = T>>(self, values: U, err: impl ToString) -> Self
+    where
+        T: PartialEq + Display + 'static,
+        Self: Sized,
+    {
+        self.inside_err(Selection(values.into_iter().collect()), err)
+    }
     /// Toggles whether a prompt message gets printed once or each time input is requested.
     fn toggle_msg_repeat(self) -> Self;
     /// Send prompts to custom writer instead of stdout
     fn prompting_on(self, prompt_output: RefCell<Box<dyn Write>>) -> Self;
     /// Send prompts to stderr instead of stdout
     fn prompting_on_stderr(self) -> Self;
+    /// Send error messages to a custom writer instead of where prompts are sent
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// # use std::cell::RefCell;
+    /// let log = std::fs::File::create("errors.log").unwrap();
+    /// let number: u32 = input()
+    ///     .msg("Number: ")
+    ///     .errors_on(RefCell::new(Box::new(log)))
+    ///     .get();
+    /// ```
+    fn errors_on(self, err_output: RefCell<Box<dyn Write>>) -> Self;
+    /// Send error messages to stderr instead of where prompts are sent
+    fn errors_on_stderr(self) -> Self;
+    /// Reads input from a custom reader instead of stdin.
+    ///
+    /// Nobody types the lines of a reader, so batch mode is on unless [`InputBuild::batch`]
+    /// turns it off.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// # use std::{cell::RefCell, fs::File, io::BufReader};
+    /// let answers = BufReader::new(File::open("answers.txt").unwrap());
+    /// let name: String = input().reading_from(RefCell::new(Box::new(answers))).get();
+    /// ```
+    fn reading_from(self, input: RefCell<Box<dyn BufRead>>) -> Self;
+    /// Prompts on and reads from the terminal even when stdin or stdout are redirected.
+    ///
+    /// This keeps prompts out of piped output (`mytool | jq`) and answers out of piped input
+    /// (`cat data | mytool`). Error messages are also written to the terminal unless
+    /// [`InputBuild::errors_on`] is used.
+    ///
+    /// `try_get` returns an error if there is no terminal.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// let overwrite: bool = input().msg("Overwrite existing file? ").use_tty().get();
+    /// ```
+    fn use_tty(self) -> Self;
+    /// Remembers accepted values between runs so they can be recalled later.
+    ///
+    /// Only values that pass every test are stored.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// let host: String = input().msg("Host: ").history("hosts").get();
+    /// ```
+    ///
+    /// See [`History`] for the available settings.
+    fn history(self, history: impl Into<History>) -> Self;
+    /// Adds a source of tab completions.
+    ///
+    /// Values given to [`InputBuild::select`] are always offered as completions.
+    /// See the [`completion`] module for built in completers and how Tab is handled.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// use read_input::completion::PathCompleter;
+    /// let path: String = input().msg("File: ").completer(PathCompleter).get();
+    /// ```
+    fn completer(self, completer: impl Completer + 'static) -> Self;
+    /// Translates the built-in messages used by this builder.
+    ///
+    /// Messages that are not translated here use the catalogue of the current locale.
+    /// See the [`messages`] module.
+    fn translate(self, translate: impl Translate + 'static) -> Self;
+    /// Limits how many times the user can try to input a valid value.
+    ///
+    /// When the limit is reached `try_get` returns an error with the last error message.
+    fn max_attempts(self, attempts: usize) -> Self;
+    /// Styles prompts, hints, errors and accepted values.
+    ///
+    /// Builders without a theme use [`theme::default`]. See the [`theme`] module.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// use read_input::theme::Theme;
+    /// let name: String = input().msg("Name: ").theme(Theme::colourful().ascii()).get();
+    /// ```
+    fn theme(self, theme: Theme) -> Self;
+    /// Proposes a value for the user to accept or change.
+    ///
+    /// The text is shown in the prompt like a default value and is used as the input when the
+    /// user submits an empty line, so it still has to pass every test. Unlike
+    /// [`InputBuilder::default`] it does not need to be a valid `T`.
+    ///
+    /// Initial text takes the place of a default value. If both are set, an empty line submits
+    /// the initial text, the prompt shows it instead of the default value and the default value
+    /// is never used.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// // Prints "Project name [my-project]: "
+    /// let name: String = input().msg("Project name: ").initial_text("my-project").get();
+    /// ```
+    fn initial_text(self, text: impl ToString) -> Self;
+    /// Turns batch mode on or off.
+    ///
+    /// In batch mode the first invalid line ends reading instead of asking again. A report with
+    /// the line number, the line and the error message is written to the error output, or to
+    /// stderr if none is set, and `try_get` returns an error that holds an
+    /// [`InputError`](crate::error::InputError). Reaching the end of input is also an error.
+    ///
+    /// By default batch mode is on when stdin is not a terminal or [`InputBuild::reading_from`]
+    /// is used, unless [`InputBuild::use_tty`] is used.
+    ///
+    /// Line numbers of stdin count the lines read by every builder, so they stay right when a
+    /// script asks several questions from one piped file. With [`InputBuild::reading_from`] they
+    /// count the lines read by this builder and its clones. Lines read some other way, for
+    /// example with a [`Scanner`](crate::scanner::Scanner), are not counted.
+    ///
+    /// ```text
+    /// error: Must be at least 18.
+    ///  --> line 3
+    ///   |
+    /// 3 | 17
+    ///   |
+    /// ```
+    fn batch(self, batch: bool) -> Self;
+    /// Replaces bytes that are not valid UTF-8 with `�` instead of asking again.
+    ///
+    /// Without this setting lines that are not valid UTF-8 are rejected with an error message,
+    /// unless the builder keeps raw bytes like [`input_os_string`](crate::shortcut::input_os_string)
+    /// and [`input_path`](crate::shortcut::input_path) do.
+    fn lossy_utf8(self) -> Self;
 }
 
 /// A set of validation tests that use `InputBuild::test` under the hood.
+///
+/// Each constraint has an error message that describes it, such as "Must be at least 4.".
+/// The `_err` variants replace it with a custom error message.
 pub trait InputConstraints<T>: InputBuild<T>
 where
-    T: FromStr + PartialOrd + 'static,
+    T: FromStr + PartialOrd + Display + 'static,
     Self: Sized,
 {
     /// Sets a minimum input value.
@@ -260,11 +460,11 @@ where
     }
     /// Sets a restricted input value.
     fn not(self, this: T) -> Self {
-        self.add_test(move |x: &T| *x != this)
+        self.inside(NotEqual(this))
     }
     /// Sets a restricted input value with custom error message.
     fn not_err(self, this: T, err: impl ToString) -> Self {
-        self.add_err_test(move |x: &T| *x != this, err)
+        self.inside_err(NotEqual(this), err)
     }
 }
 
@@ -274,12 +474,28 @@ pub(crate) struct Prompt {
     pub repeat: bool,
 }
 
-#[derive(Clone)]
 pub(crate) struct Test<T> {
     pub func: Rc<dyn Fn(&T) -> bool>,
     pub err: Option<String>,
+    pub candidates: Option<Vec<String>>,
+    pub message: Option<Message>,
+}
+
+// Not derived so that `T` does not have to be `Clone`.
+impl<T> Clone for Test<T> {
+    fn clone(&self) -> Self {
+        Self {
+            func: self.func.clone(),
+            err: self.err.clone(),
+            candidates: self.candidates.clone(),
+            message: self.message.clone(),
+        }
+    }
 }
 
+// Turns a parse error into an error message.
+type ErrMatch<T> = Rc<dyn Fn(&<T as FromStr>::Err) -> Option<String>>;
+
 /// 'builder' used to store the settings that are used to fetch input.
 ///
 /// `.get()` method only takes these settings by reference so can be called multiple times.
@@ -287,10 +503,26 @@ pub(crate) struct Test<T> {
 /// This type does not have support for default input value.
 pub struct InputBuilder<T: FromStr> {
     msg: Prompt,
-    err: String,
+    err: Option<String>,
     tests: Vec<Test<T>>,
-    err_match: Rc<dyn Fn(&T::Err) -> Option<String>>,
+    err_match: ErrMatch<T>,
     prompt_output: RefCell<Box<dyn Write>>,
+    prompt_terminal: bool,
+    err_output: Option<Rc<RefCell<Box<dyn Write>>>>,
+    err_terminal: bool,
+    tty: bool,
+    history: Option<History>,
+    completers: Vec<Rc<dyn Completer>>,
+    translate: Option<Rc<dyn Translate>>,
+    display: Option<fn(&T) -> String>,
+    max_attempts: Option<usize>,
+    theme: Option<Theme>,
+    initial_text: Option<String>,
+    batch: Option<bool>,
+    lossy: bool,
+    from_bytes: Option<fn(&[u8]) -> T>,
+    maps: Vec<Rc<dyn Fn(T) -> T>>,
+    source: Rc<RefCell<Source>>,
 }
 
 impl<T: FromStr> InputBuilder<T> {
@@ -301,10 +533,29 @@ impl<T: FromStr> InputBuilder<T> {
                 msg: String::new(),
                 repeat: false,
             },
-            err: DEFAULT_ERR.to_string(),
+            err: None,
             tests: Vec::new(),
             err_match: Rc::new(|_| None),
             prompt_output: RefCell::new(Box::new(std::io::stdout())),
+            prompt_terminal: std::io::stdout().is_terminal(),
+            err_output: None,
+            err_terminal: false,
+            tty: false,
+            history: None,
+            completers: Vec::new(),
+            translate: None,
+            display: None,
+            max_attempts: None,
+            theme: None,
+            initial_text: None,
+            batch: None,
+            lossy: false,
+            from_bytes: None,
+            maps: Vec::new(),
+            source: Rc::new(RefCell::new(Source {
+                reader: None,
+                lines: 0,
+            })),
         }
     }
     /// 'gets' the input form the user.
@@ -319,18 +570,13 @@ impl<T: FromStr> InputBuilder<T> {
     ///
     /// Returns `Err` if unable to read input line.
     pub fn try_get(&self) -> io::Result<T> {
-        read_input::<T>(
-            &self.msg,
-            &self.err,
-            None,
-            &self.tests,
-            &*self.err_match,
-            &mut (*self.prompt_output.borrow_mut()),
-        )
+        read_input::<T>(self, None, None)
     }
     /// Changes or adds a default input value.
     ///
     /// If the user presses enter before typing anything `.get()` will return a default value when [InputBuilder::default] is used.
+    /// [`InputBuild::initial_text`] takes precedence over it.
+    /// Use [`InputBuilderOnce::show_default`] to show the default value in the prompt.
     ///
     /// ```rust
     /// # use read_input::prelude::*;
@@ -339,12 +585,80 @@ impl<T: FromStr> InputBuilder<T> {
     pub fn default(self, default: T) -> InputBuilderOnce<T> {
         InputBuilderOnce {
             builder: self,
-            default: Some(default),
+            default: Some(DefaultValue::Value(default)),
+            default_env: None,
+            show_default: None,
+        }
+    }
+    /// Adds a default input value that is only computed when it is needed.
+    ///
+    /// `default` is called at most once, when the user presses enter before typing anything or
+    /// when the default value is shown in the prompt.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// let name: String = input()
+    ///     .msg("Name: ")
+    ///     .default_with(|| std::env::var("USER").unwrap_or_default())
+    ///     .get();
+    /// ```
+    pub fn default_with<F: Fn() -> T + 'static>(self, default: F) -> InputBuilderOnce<T> {
+        InputBuilderOnce {
+            builder: self,
+            default: Some(DefaultValue::With(Rc::new(default))),
+            default_env: None,
+            show_default: None,
         }
     }
+    /// Takes the default input value from an environment variable.
+    ///
+    /// The variable must pass the same parsing and tests as typed input, otherwise `try_get`
+    /// returns an error naming the variable. If it is not set or empty there is no default value.
+    ///
+    /// When input is not read from a terminal, for example in scripts, the value of the variable
+    /// is returned without prompting.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// let port: u16 = input().msg("Port: ").default_from_env("APP_PORT").get();
+    /// ```
+    pub fn default_from_env(self, name: impl ToString) -> InputBuilderOnce<T> {
+        InputBuilderOnce {
+            builder: self,
+            default: None,
+            default_env: Some(name.to_string()),
+            show_default: None,
+        }
+    }
+    // Builds values from lines that are not valid UTF-8.
+    pub(crate) fn keep_bytes(mut self, from_bytes: fn(&[u8]) -> T) -> Self {
+        self.from_bytes = Some(from_bytes);
+        self
+    }
+    // Changes each parsed value before it is tested.
+    pub(crate) fn map(mut self, map: impl Fn(T) -> T + 'static) -> Self {
+        self.maps.push(Rc::new(map));
+        self
+    }
     // Internal function for adding tests and constraints.
     fn test_err_opt(mut self, func: Rc<dyn Fn(&T) -> bool>, err: Option<String>) -> Self {
-        self.tests.push(Test { func, err });
+        self.tests.push(Test {
+            func,
+            err,
+            candidates: None,
+            message: None,
+        });
+        self
+    }
+    // Internal function for adding `inside` constraints.
+    fn inside_err_opt<U: InsideFunc<T>>(mut self, constraint: U, err: Option<String>) -> Self {
+        self.display = self.display.or_else(|| constraint.display_func());
+        self.tests.push(Test {
+            candidates: constraint.candidates(),
+            message: constraint.message(),
+            func: constraint.contains_func(),
+            err,
+        });
         self
     }
 }
@@ -365,7 +679,7 @@ impl<T: FromStr> InputBuild<T> for InputBuilder<T> {
         self
     }
     fn err(mut self, err: impl ToString) -> Self {
-        self.err = err.to_string();
+        self.err = Some(err.to_string());
         self
     }
 
@@ -390,10 +704,10 @@ impl<T: FromStr> InputBuild<T> for InputBuilder<T> {
         self
     }
     fn inside<U: InsideFunc<T>>(self, constraint: U) -> Self {
-        self.test_err_opt(constraint.contains_func(), None)
+        self.inside_err_opt(constraint, None)
     }
     fn inside_err<U: InsideFunc<T>>(self, constraint: U, err: impl ToString) -> Self {
-        self.test_err_opt(constraint.contains_func(), Some(err.to_string()))
+        self.inside_err_opt(constraint, Some(err.to_string()))
     }
     fn toggle_msg_repeat(mut self) -> Self {
         self.msg.repeat = !self.msg.repeat;
@@ -402,15 +716,83 @@ impl<T: FromStr> InputBuild<T> for InputBuilder<T> {
 
     fn prompting_on(mut self, prompt_output: RefCell<Box<dyn Write>>) -> Self {
         self.prompt_output = prompt_output;
+        self.prompt_terminal = false;
         self
     }
 
-    fn prompting_on_stderr(self) -> Self {
-        self.prompting_on(RefCell::new(Box::new(std::io::stderr())))
+    fn prompting_on_stderr(mut self) -> Self {
+        self.prompt_output = RefCell::new(Box::new(std::io::stderr()));
+        self.prompt_terminal = std::io::stderr().is_terminal();
+        self
+    }
+
+    fn errors_on(mut self, err_output: RefCell<Box<dyn Write>>) -> Self {
+        self.err_output = Some(Rc::new(err_output));
+        self.err_terminal = false;
+        self
+    }
+
+    fn errors_on_stderr(mut self) -> Self {
+        self.err_output = Some(Rc::new(RefCell::new(Box::new(std::io::stderr()))));
+        self.err_terminal = std::io::stderr().is_terminal();
+        self
+    }
+
+    fn reading_from(mut self, input: RefCell<Box<dyn BufRead>>) -> Self {
+        self.source = Rc::new(RefCell::new(Source {
+            reader: Some(input.into_inner()),
+            lines: 0,
+        }));
+        self
+    }
+
+    fn use_tty(mut self) -> Self {
+        self.tty = true;
+        self
+    }
+
+    fn history(mut self, history: impl Into<History>) -> Self {
+        self.history = Some(history.into());
+        self
+    }
+
+    fn completer(mut self, completer: impl Completer + 'static) -> Self {
+        self.completers.push(Rc::new(completer));
+        self
+    }
+
+    fn translate(mut self, translate: impl Translate + 'static) -> Self {
+        self.translate = Some(Rc::new(translate));
+        self
+    }
+
+    fn max_attempts(mut self, attempts: usize) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    fn initial_text(mut self, text: impl ToString) -> Self {
+        self.initial_text = Some(text.to_string());
+        self
+    }
+
+    fn batch(mut self, batch: bool) -> Self {
+        self.batch = Some(batch);
+        self
+    }
+
+    fn lossy_utf8(mut self) -> Self {
+        self.lossy = true;
+        self
     }
 }
 
-impl<T: FromStr + PartialOrd + 'static> InputConstraints<T> for InputBuilder<T> {}
+impl<T: FromStr + PartialOrd + Display + 'static> InputConstraints<T> for InputBuilder<T> {}
 
 impl<T: FromStr> Default for InputBuilder<T> {
     fn default() -> Self {
@@ -426,10 +808,55 @@ impl<T: FromStr + Clone> Clone for InputBuilder<T> {
             tests: self.tests.clone(),
             err_match: self.err_match.clone(),
             prompt_output: RefCell::new(Box::new(std::io::stdout())),
+            prompt_terminal: std::io::stdout().is_terminal(),
+            err_output: self.err_output.clone(),
+            err_terminal: self.err_terminal,
+            tty: self.tty,
+            history: self.history.clone(),
+            completers: self.completers.clone(),
+            translate: self.translate.clone(),
+            display: self.display,
+            max_attempts: self.max_attempts,
+            theme: self.theme.clone(),
+            initial_text: self.initial_text.clone(),
+            batch: self.batch,
+            lossy: self.lossy,
+            from_bytes: self.from_bytes,
+            maps: self.maps.clone(),
+            source: self.source.clone(),
         }
     }
 }
 
+// Default value of an `InputBuilderOnce`.
+#[derive(Clone)]
+pub(crate) enum DefaultValue<T> {
+    Value(T),
+    With(Rc<dyn Fn() -> T>),
+}
+
+impl<T> DefaultValue<T> {
+    // Computes the value if that has not been done yet.
+    pub(crate) fn cached(&mut self) -> &T {
+        if let Self::With(default) = self {
+            *self = Self::Value(default());
+        }
+        match self {
+            Self::Value(value) => value,
+            Self::With(_) => unreachable!(),
+        }
+    }
+    pub(crate) fn value(self) -> T {
+        match self {
+            Self::Value(value) => value,
+            Self::With(default) => default(),
+        }
+    }
+}
+
+// Formats the default value shown in the prompt.
+type ShowDefault<T> = Rc<dyn Fn(&T) -> String>;
+
 /// 'builder' used to store the settings that are used to fetch input.
 ///
 /// `.get()` method takes ownership of the settings so can be called only once without cloning.
@@ -437,7 +864,34 @@ impl<T: FromStr + Clone> Clone for InputBuilder<T> {
 /// This type has support for default input value.
 pub struct InputBuilderOnce<T: FromStr> {
     builder: InputBuilder<T>,
-    default: Option<T>,
+    default: Option<DefaultValue<T>>,
+    default_env: Option<String>,
+    show_default: Option<ShowDefault<T>>,
+}
+
+// Parses the value of the environment variable `name` with the tests of `builder`.
+pub(crate) fn env_default<T: FromStr>(
+    builder: &InputBuilder<T>,
+    name: &str,
+    value: Option<OsString>,
+) -> io::Result<Option<T>> {
+    // A variable set to an empty value, such as `APP_PORT=`, counts as not set.
+    let value = match value.filter(|x| !x.is_empty()) {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+    let value = value.to_str().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("environment variable {} is not valid unicode", name),
+        )
+    })?;
+    parse_input(value, builder, 1).map(Some).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("environment variable {} is not valid: {}", name, e.msg),
+        )
+    })
 }
 
 impl<T: FromStr> InputBuilderOnce<T> {
@@ -452,18 +906,58 @@ impl<T: FromStr> InputBuilderOnce<T> {
     /// # Errors
     ///
     /// Returns `Err` if unable to read input line.
-    pub fn try_get(self) -> io::Result<T> {
-        read_input::<T>(
-            &self.builder.msg,
-            &self.builder.err,
-            self.default,
-            &self.builder.tests,
-            &*self.builder.err_match,
-            &mut (*self.builder.prompt_output.borrow_mut()),
-        )
+    pub fn try_get(mut self) -> io::Result<T> {
+        if let Some(name) = &self.default_env {
+            if let Some(value) = env_default(&self.builder, name, env::var_os(name))? {
+                if !is_interactive(&self.builder) {
+                    return Ok(value);
+                }
+                self.default = Some(DefaultValue::Value(value));
+            }
+        }
+        let hint = match (&self.show_default, &mut self.default) {
+            (Some(show), Some(default)) => Some(show(default.cached())),
+            _ => None,
+        };
+        read_input::<T>(&self.builder, self.default, hint)
+    }
+    /// Shows the default value in the prompt.
+    ///
+    /// The value is written in brackets before the colon that ends the prompt message.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// // Prints "Port [8080]: "
+    /// let port: u16 = input().msg("Port: ").default(8080).show_default().get();
+    /// ```
+    pub fn show_default(self) -> Self
+    where
+        T: Display,
+    {
+        self.default_format(|x: &T| format!("[{}]", x))
+    }
+    /// Shows the default value in the prompt formatted by a custom function.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// // Prints "Timeout (default 30s): "
+    /// let timeout: u64 = input()
+    ///     .msg("Timeout: ")
+    ///     .default(30)
+    ///     .default_format(|x| format!("(default {}s)", x))
+    ///     .get();
+    /// ```
+    pub fn default_format<F: Fn(&T) -> String + 'static>(mut self, format: F) -> Self {
+        self.show_default = Some(Rc::new(format));
+        self
+    }
+    /// Stops showing the default value in the prompt.
+    pub fn hide_default(mut self) -> Self {
+        self.show_default = None;
+        self
     }
     // Function that makes it less verbose to change settings of internal `InputBuilder`.
-    fn internal<F>(self, with: F) -> Self
+    pub(crate) fn internal<F>(self, with: F) -> Self
     where
         F: FnOnce(InputBuilder<T>) -> InputBuilder<T>,
     {
@@ -517,11 +1011,59 @@ impl<T: FromStr> InputBuild<T> for InputBuilderOnce<T> {
     }
 
     fn prompting_on_stderr(self) -> Self {
-        self.internal(|x| x.prompting_on(RefCell::new(Box::new(std::io::stderr()))))
+        self.internal(InputBuild::prompting_on_stderr)
+    }
+
+    fn errors_on(self, err_output: RefCell<Box<dyn Write>>) -> Self {
+        self.internal(|x| x.errors_on(err_output))
+    }
+
+    fn errors_on_stderr(self) -> Self {
+        self.internal(InputBuild::errors_on_stderr)
+    }
+
+    fn reading_from(self, input: RefCell<Box<dyn BufRead>>) -> Self {
+        self.internal(|x| x.reading_from(input))
+    }
+
+    fn use_tty(self) -> Self {
+        self.internal(InputBuild::use_tty)
+    }
+
+    fn history(self, history: impl Into<History>) -> Self {
+        self.internal(|x| x.history(history))
+    }
+
+    fn completer(self, completer: impl Completer + 'static) -> Self {
+        self.internal(|x| x.completer(completer))
+    }
+
+    fn translate(self, translate: impl Translate + 'static) -> Self {
+        self.internal(|x| x.translate(translate))
+    }
+
+    fn max_attempts(self, attempts: usize) -> Self {
+        self.internal(|x| x.max_attempts(attempts))
+    }
+
+    fn theme(self, theme: Theme) -> Self {
+        self.internal(|x| x.theme(theme))
+    }
+
+    fn initial_text(self, text: impl ToString) -> Self {
+        self.internal(|x| x.initial_text(text))
+    }
+
+    fn batch(self, batch: bool) -> Self {
+        self.internal(|x| x.batch(batch))
+    }
+
+    fn lossy_utf8(self) -> Self {
+        self.internal(InputBuild::lossy_utf8)
     }
 }
 
-impl<T: FromStr + PartialOrd + 'static> InputConstraints<T> for InputBuilderOnce<T> {}
+impl<T: FromStr + PartialOrd + Display + 'static> InputConstraints<T> for InputBuilderOnce<T> {}
 
 impl<T> Clone for InputBuilderOnce<T>
 where
@@ -530,7 +1072,9 @@ where
     fn clone(&self) -> Self {
         Self {
             default: self.default.clone(),
+            default_env: self.default_env.clone(),
             builder: self.builder.clone(),
+            show_default: self.show_default.clone(),
         }
     }
 }
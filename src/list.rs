@@ -0,0 +1,343 @@
+//! Reading several values from a single line.
+//!
+//! [`input_list`](crate::shortcut::input_list) creates a [`ListBuilder`]. Settings from
+//! [`InputBuild`] and [`InputConstraints`] apply to each item and the methods of
+//! [`ListBuilder`] apply to the list as a whole.
+//!
+//! ```no_run
+//! # use read_input::prelude::*;
+//! let ports: Vec<u16> = input_list()
+//!     .msg("Ports: ")
+//!     .min(1024)
+//!     .min_items(1)
+//!     .unique()
+//!     .get();
+//! ```
+
+use crate::{
+    completion::Completer,
+    core::{check, parse_error, read_with, translated, Rejection},
+    history::History,
+    messages::{Message, Translate},
+    test_generators::InsideFunc,
+    theme::Theme,
+    tuple::short_type_name,
+    InputBuild, InputBuilder, InputConstraints,
+};
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    io::{self, BufRead, Write},
+    rc::Rc,
+    str::FromStr,
+};
+
+/// Where a line is split into items.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Delimiter {
+    /// Items are separated by commas. This is the default.
+    Comma,
+    /// Items are separated by any amount of whitespace.
+    Whitespace,
+    /// Items are separated by this text.
+    Custom(String),
+}
+
+impl Delimiter {
+    pub(crate) fn split<'a>(&self, line: &'a str) -> Vec<&'a str> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Vec::new();
+        }
+        match self {
+            Self::Comma => line.split(',').map(str::trim).collect(),
+            Self::Whitespace => line.split_whitespace().collect(),
+            Self::Custom(delimiter) => line.split(delimiter.as_str()).map(str::trim).collect(),
+        }
+    }
+    fn join(&self, items: &[&str]) -> String {
+        match self {
+            Self::Comma => items.join(", "),
+            Self::Whitespace => items.join(" "),
+            Self::Custom(delimiter) => items.join(delimiter),
+        }
+    }
+}
+
+impl From<char> for Delimiter {
+    fn from(delimiter: char) -> Self {
+        Self::Custom(delimiter.to_string())
+    }
+}
+
+impl From<&str> for Delimiter {
+    fn from(delimiter: &str) -> Self {
+        Self::Custom(delimiter.to_string())
+    }
+}
+
+impl From<String> for Delimiter {
+    fn from(delimiter: String) -> Self {
+        Self::Custom(delimiter)
+    }
+}
+
+// Test of the whole list, given the parsed values and the items they were parsed from.
+type ListTest<T> = Rc<dyn Fn(&[T], &[&str]) -> Option<Message>>;
+
+/// 'builder' used to store the settings that are used to fetch a list of values from one line.
+///
+/// `.get()` method only takes these settings by reference so can be called multiple times.
+pub struct ListBuilder<T: FromStr> {
+    builder: InputBuilder<T>,
+    delimiter: Delimiter,
+    tests: Vec<ListTest<T>>,
+}
+
+impl<T: FromStr> ListBuilder<T> {
+    /// Creates a new instance of `ListBuilder` with default settings.
+    pub fn new() -> Self {
+        Self {
+            builder: InputBuilder::new(),
+            delimiter: Delimiter::Comma,
+            tests: Vec::new(),
+        }
+    }
+    /// 'gets' the input form the user.
+    ///
+    /// Panics if unable to read input line.
+    pub fn get(&self) -> Vec<T> {
+        self.try_get().expect("Failed to read line")
+    }
+    /// 'gets' the input form the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if unable to read input line.
+    pub fn try_get(&self) -> io::Result<Vec<T>> {
+        read_with(&self.builder, None, None, |input, _, attempt| {
+            self.parse(input, attempt)
+        })
+    }
+    /// Sets where the line is split into items.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// use read_input::list::Delimiter;
+    /// let words: Vec<String> = input_list().delimiter(Delimiter::Whitespace).get();
+    /// let path: Vec<String> = input_list().delimiter(':').get();
+    /// ```
+    pub fn delimiter(mut self, delimiter: impl Into<Delimiter>) -> Self {
+        self.delimiter = delimiter.into();
+        self
+    }
+    /// Sets the least number of items.
+    pub fn min_items(self, min: usize) -> Self {
+        self.list_test(move |values, _| {
+            if values.len() < min {
+                Some(Message::TooFewItems(min))
+            } else {
+                None
+            }
+        })
+    }
+    /// Sets the greatest number of items.
+    pub fn max_items(self, max: usize) -> Self {
+        self.list_test(move |values, _| {
+            if values.len() > max {
+                Some(Message::TooManyItems(max))
+            } else {
+                None
+            }
+        })
+    }
+    /// Rejects lists where an item is given more than once.
+    pub fn unique(self) -> Self
+    where
+        T: PartialEq,
+    {
+        self.list_test(|values, items| {
+            (1..values.len())
+                .find(|&i| values[..i].contains(&values[i]))
+                .map(|i| Message::RepeatedItem(i + 1, items[i].to_string()))
+        })
+    }
+    // Internal function for adding tests of the whole list.
+    fn list_test<F>(mut self, test: F) -> Self
+    where
+        F: Fn(&[T], &[&str]) -> Option<Message> + 'static,
+    {
+        self.tests.push(Rc::new(test));
+        self
+    }
+    pub(crate) fn parse(&self, input: &str, attempt: usize) -> Result<Vec<T>, Rejection> {
+        let items = self.delimiter.split(input);
+        let mut values = Vec::with_capacity(items.len());
+        for (i, item) in items.iter().enumerate() {
+            let checked = match T::from_str(item) {
+                Ok(value) => check(value, item, &self.builder, attempt),
+                Err(error) => match parse_error(&error, item, &self.builder, attempt) {
+                    Some(msg) => Err(Rejection {
+                        msg,
+                        suggestion: None,
+                    }),
+                    // Without a custom message the item is named along with its type.
+                    None => {
+                        let message =
+                            Message::ItemNotValid(i + 1, item.to_string(), short_type_name::<T>());
+                        return Err(Rejection {
+                            msg: translated(&self.builder, &message),
+                            suggestion: None,
+                        });
+                    }
+                },
+            };
+            match checked {
+                Ok(value) => values.push(value),
+                Err(e) => {
+                    let message = Message::Item(i + 1, item.to_string(), e.msg);
+                    // A suggested item is offered as the whole line with that item replaced.
+                    let suggestion = e.suggestion.map(|suggestion| {
+                        let mut items = items.clone();
+                        items[i] = &suggestion;
+                        self.delimiter.join(&items)
+                    });
+                    return Err(Rejection {
+                        msg: translated(&self.builder, &message),
+                        suggestion,
+                    });
+                }
+            }
+        }
+        match self.tests.iter().find_map(|test| test(&values, &items)) {
+            Some(message) => Err(Rejection {
+                msg: translated(&self.builder, &message),
+                suggestion: None,
+            }),
+            None => Ok(values),
+        }
+    }
+    // Function that makes it less verbose to change settings of internal `InputBuilder`.
+    pub(crate) fn internal<F>(self, with: F) -> Self
+    where
+        F: FnOnce(InputBuilder<T>) -> InputBuilder<T>,
+    {
+        Self {
+            builder: with(self.builder),
+            ..self
+        }
+    }
+}
+
+impl<T: FromStr> InputBuild<T> for ListBuilder<T> {
+    fn msg(self, msg: impl ToString) -> Self {
+        self.internal(|x| x.msg(msg))
+    }
+    fn repeat_msg(self, msg: impl ToString) -> Self {
+        self.internal(|x| x.repeat_msg(msg))
+    }
+    fn err(self, err: impl ToString) -> Self {
+        self.internal(|x| x.err(err))
+    }
+    fn add_test<F: Fn(&T) -> bool + 'static>(self, test: F) -> Self {
+        self.internal(|x| x.add_test(test))
+    }
+    fn add_err_test<F>(self, test: F, err: impl ToString) -> Self
+    where
+        F: Fn(&T) -> bool + 'static,
+    {
+        self.internal(|x| x.add_err_test(test, err))
+    }
+    fn clear_tests(self) -> Self {
+        self.internal(InputBuild::clear_tests)
+    }
+    fn err_match<F>(self, err_match: F) -> Self
+    where
+        F: Fn(&T::Err) -> Option<String> + 'static,
+    {
+        self.internal(|x| x.err_match(err_match))
+    }
+    fn inside<U: InsideFunc<T>>(self, constraint: U) -> Self {
+        self.internal(|x| x.inside(constraint))
+    }
+    fn inside_err<U: InsideFunc<T>>(self, constraint: U, err: impl ToString) -> Self {
+        self.internal(|x| x.inside_err(constraint, err))
+    }
+    fn toggle_msg_repeat(self) -> Self {
+        self.internal(InputBuild::toggle_msg_repeat)
+    }
+
+    fn prompting_on(self, prompt_output: RefCell<Box<dyn Write>>) -> Self {
+        self.internal(|x| x.prompting_on(prompt_output))
+    }
+
+    fn prompting_on_stderr(self) -> Self {
+        self.internal(InputBuild::prompting_on_stderr)
+    }
+
+    fn errors_on(self, err_output: RefCell<Box<dyn Write>>) -> Self {
+        self.internal(|x| x.errors_on(err_output))
+    }
+
+    fn errors_on_stderr(self) -> Self {
+        self.internal(InputBuild::errors_on_stderr)
+    }
+
+    fn reading_from(self, input: RefCell<Box<dyn BufRead>>) -> Self {
+        self.internal(|x| x.reading_from(input))
+    }
+
+    fn use_tty(self) -> Self {
+        self.internal(InputBuild::use_tty)
+    }
+
+    fn history(self, history: impl Into<History>) -> Self {
+        self.internal(|x| x.history(history))
+    }
+
+    fn completer(self, completer: impl Completer + 'static) -> Self {
+        self.internal(|x| x.completer(completer))
+    }
+
+    fn translate(self, translate: impl Translate + 'static) -> Self {
+        self.internal(|x| x.translate(translate))
+    }
+
+    fn max_attempts(self, attempts: usize) -> Self {
+        self.internal(|x| x.max_attempts(attempts))
+    }
+
+    fn theme(self, theme: Theme) -> Self {
+        self.internal(|x| x.theme(theme))
+    }
+
+    fn initial_text(self, text: impl ToString) -> Self {
+        self.internal(|x| x.initial_text(text))
+    }
+
+    fn batch(self, batch: bool) -> Self {
+        self.internal(|x| x.batch(batch))
+    }
+
+    fn lossy_utf8(self) -> Self {
+        self.internal(InputBuild::lossy_utf8)
+    }
+}
+
+impl<T: FromStr + PartialOrd + Display + 'static> InputConstraints<T> for ListBuilder<T> {}
+
+impl<T: FromStr> Default for ListBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FromStr + Clone> Clone for ListBuilder<T> {
+    fn clone(&self) -> Self {
+        Self {
+            builder: self.builder.clone(),
+            delimiter: self.delimiter.clone(),
+            tests: self.tests.clone(),
+        }
+    }
+}
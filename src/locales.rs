@@ -0,0 +1,351 @@
+// Catalogues bundled with the crate.
+
+use crate::messages::{Kind, Message, Translate};
+use std::sync::Arc;
+
+pub(crate) fn bundled(code: &str) -> Option<Arc<dyn Translate + Send + Sync>> {
+    Some(match code {
+        "en" => Arc::new(|_: &Message| None),
+        "de" => Arc::new(german),
+        "fr" => Arc::new(french),
+        "es" => Arc::new(spanish),
+        _ => return None,
+    })
+}
+
+fn plural(count: usize, one: &'static str, many: &'static str) -> &'static str {
+    if count == 1 {
+        one
+    } else {
+        many
+    }
+}
+
+fn ordinal(n: usize, words: [&'static str; 8]) -> Option<&'static str> {
+    words.get(n.checked_sub(1)?).copied()
+}
+
+fn german(message: &Message) -> Option<String> {
+    Some(match message {
+        Message::AtLeast(min) => format!("Muss mindestens {} sein.", min),
+        Message::AtMost(max) => format!("Darf höchstens {} sein.", max),
+        Message::Below(max) => format!("Muss kleiner als {} sein.", max),
+        Message::Between(min, max) => format!("Muss zwischen {} und {} liegen.", min, max),
+        Message::Range(min, max) => {
+            format!("Muss mindestens {} und kleiner als {} sein.", min, max)
+        }
+        Message::Not(this) => format!("Darf nicht {} sein.", this),
+        Message::OneOf(values) => format!("Muss einer dieser Werte sein: {}.", values.join(", ")),
+        Message::Invalid => "Dieser Wert ist ungültig. Bitte versuchen Sie es erneut".to_string(),
+        Message::DidYouMean(input, suggestion) => format!(
+            "`{}` ist ungültig — meinten Sie `{}`? Mit y übernehmen.",
+            input, suggestion
+        ),
+        Message::Suggest(suggestion) => format!("Meinten Sie `{}`? Mit y übernehmen.", suggestion),
+        Message::Prompt(kind) => match kind {
+            Kind::Bool => "Bitte true oder false eingeben: ",
+            Kind::Char => "Bitte ein Zeichen eingeben: ",
+            Kind::Integer => "Bitte eine ganze Zahl eingeben: ",
+            Kind::PositiveInteger => "Bitte eine positive ganze Zahl eingeben: ",
+            Kind::Number => "Bitte eine Zahl eingeben: ",
+        }
+        .to_string(),
+        Message::KindErr(kind) => match kind {
+            Kind::Bool => "Nur true oder false eingeben.",
+            Kind::Char => "Nur ein einzelnes Zeichen eingeben.",
+            Kind::Integer => "Nur ganze Zahlen eingeben.",
+            Kind::PositiveInteger => "Nur positive ganze Zahlen eingeben.",
+            Kind::Number => "Nur Zahlen oder einen Dezimalpunkt eingeben.",
+        }
+        .to_string(),
+        Message::Item(n, input, err) => format!("Eintrag {} (`{}`): {}", n, input, err),
+        Message::ItemNotValid(n, input, kind) => format!(
+            "Eintrag {} (`{}`) ist kein gültiger Wert vom Typ {}.",
+            n, input, kind
+        ),
+        Message::TooFewItems(min) => format!(
+            "Muss mindestens {} {} haben.",
+            min,
+            plural(*min, "Eintrag", "Einträge")
+        ),
+        Message::TooManyItems(max) => format!(
+            "Darf höchstens {} {} haben.",
+            max,
+            plural(*max, "Eintrag", "Einträge")
+        ),
+        Message::RepeatedItem(n, input) => {
+            format!("Eintrag {} (`{}`) kommt mehrfach vor.", n, input)
+        }
+        Message::ItemPrompt(n) => format!("Eintrag {}: ", n),
+        Message::ValueCount(expected, given) => format!(
+            "Es werden {} Werte erwartet, aber {} wurden eingegeben.",
+            expected, given
+        ),
+        Message::NotValidAt(n, kind) => match ordinal(
+            *n,
+            [
+                "erste", "zweite", "dritte", "vierte", "fünfte", "sechste", "siebte", "achte",
+            ],
+        ) {
+            Some(nth) => format!("Der {} Wert ist kein gültiger Wert vom Typ {}.", nth, kind),
+            None => format!("Wert {} ist kein gültiger Wert vom Typ {}.", n, kind),
+        },
+        Message::Pattern(pattern) => format!("Muss die Form `{}` haben.", pattern),
+        Message::InvalidUtf8 => "Die Eingabe ist kein gültiges UTF-8.".to_string(),
+        Message::Exists => "Muss ein vorhandener Pfad sein.".to_string(),
+        Message::NotExists => "Darf noch nicht vorhanden sein.".to_string(),
+        Message::File => "Muss eine Datei sein.".to_string(),
+        Message::Directory => "Muss ein Verzeichnis sein.".to_string(),
+        Message::Extension(extensions) => {
+            format!("Muss auf eines davon enden: .{}.", extensions.join(", ."))
+        }
+        Message::Readable => "Muss lesbar sein.".to_string(),
+        Message::Writable => "Muss beschreibbar sein.".to_string(),
+        Message::MinLength(min) => format!("Muss mindestens {} Zeichen lang sein.", min),
+        Message::MaxLength(max) => format!("Darf höchstens {} Zeichen lang sein.", max),
+        Message::LengthBetween(min, max) => {
+            format!("Muss zwischen {} und {} Zeichen lang sein.", min, max)
+        }
+        Message::NotEmpty => "Darf nicht leer sein.".to_string(),
+        Message::Ascii => "Darf nur ASCII-Zeichen enthalten.".to_string(),
+        Message::Alphanumeric => "Darf nur Buchstaben und Ziffern enthalten.".to_string(),
+        Message::Matches(pattern) => format!("Muss zu `{}` passen.", pattern),
+        Message::Finite => "Muss eine endliche Zahl sein.".to_string(),
+        Message::NotNan => "Muss eine Zahl sein, nicht NaN.".to_string(),
+        Message::DecimalPlaces(0) => "Muss eine ganze Zahl sein.".to_string(),
+        Message::DecimalPlaces(places) => format!(
+            "Darf höchstens {} {} haben.",
+            places,
+            plural(*places, "Nachkommastelle", "Nachkommastellen")
+        ),
+        Message::Step(step) => format!("Muss ein Vielfaches von {} sein.", step),
+        Message::ApproxNot(this, eps) => {
+            format!("Darf nicht weniger als {} von {} entfernt sein.", eps, this)
+        }
+    })
+}
+
+fn french(message: &Message) -> Option<String> {
+    Some(match message {
+        Message::AtLeast(min) => format!("Doit être au moins {}.", min),
+        Message::AtMost(max) => format!("Doit être au plus {}.", max),
+        Message::Below(max) => format!("Doit être inférieur à {}.", max),
+        Message::Between(min, max) => format!("Doit être entre {} et {}.", min, max),
+        Message::Range(min, max) => format!("Doit être au moins {} et inférieur à {}.", min, max),
+        Message::Not(this) => format!("Ne doit pas être {}.", this),
+        Message::OneOf(values) => {
+            format!("Doit être l'une de ces valeurs : {}.", values.join(", "))
+        }
+        Message::Invalid => "Cette valeur n'est pas valide. Veuillez réessayer".to_string(),
+        Message::DidYouMean(input, suggestion) => format!(
+            "`{}` n'est pas valide — vouliez-vous dire `{}` ? Tapez y pour l'utiliser.",
+            input, suggestion
+        ),
+        Message::Suggest(suggestion) => format!(
+            "Vouliez-vous dire `{}` ? Tapez y pour l'utiliser.",
+            suggestion
+        ),
+        Message::Prompt(kind) => match kind {
+            Kind::Bool => "Veuillez saisir true ou false : ",
+            Kind::Char => "Veuillez saisir un caractère : ",
+            Kind::Integer => "Veuillez saisir un entier : ",
+            Kind::PositiveInteger => "Veuillez saisir un entier positif : ",
+            Kind::Number => "Veuillez saisir un nombre : ",
+        }
+        .to_string(),
+        Message::KindErr(kind) => match kind {
+            Kind::Bool => "Tapez seulement true ou false.",
+            Kind::Char => "Tapez un seul caractère.",
+            Kind::Integer => "Tapez seulement des entiers.",
+            Kind::PositiveInteger => "Tapez seulement des entiers positifs.",
+            Kind::Number => "Tapez seulement des chiffres ou un point décimal.",
+        }
+        .to_string(),
+        Message::Item(n, input, err) => format!("Élément {} (`{}`) : {}", n, input, err),
+        Message::ItemNotValid(n, input, kind) => format!(
+            "L'élément {} (`{}`) n'est pas un {} valide.",
+            n, input, kind
+        ),
+        Message::TooFewItems(min) => format!(
+            "Doit contenir au moins {} {}.",
+            min,
+            plural(*min, "élément", "éléments")
+        ),
+        Message::TooManyItems(max) => format!(
+            "Doit contenir au plus {} {}.",
+            max,
+            plural(*max, "élément", "éléments")
+        ),
+        Message::RepeatedItem(n, input) => {
+            format!("L'élément {} (`{}`) est donné plusieurs fois.", n, input)
+        }
+        Message::ItemPrompt(n) => format!("Élément {} : ", n),
+        Message::ValueCount(expected, given) => format!(
+            "{} valeurs sont attendues mais {} ont été saisies.",
+            expected, given
+        ),
+        Message::NotValidAt(n, kind) => match ordinal(
+            *n,
+            [
+                "première",
+                "deuxième",
+                "troisième",
+                "quatrième",
+                "cinquième",
+                "sixième",
+                "septième",
+                "huitième",
+            ],
+        ) {
+            Some(nth) => format!("La {} valeur n'est pas un {} valide.", nth, kind),
+            None => format!("La valeur {} n'est pas un {} valide.", n, kind),
+        },
+        Message::Pattern(pattern) => format!("Doit avoir la forme `{}`.", pattern),
+        Message::InvalidUtf8 => "L'entrée n'est pas de l'UTF-8 valide.".to_string(),
+        Message::Exists => "Doit être un chemin existant.".to_string(),
+        Message::NotExists => "Ne doit pas encore exister.".to_string(),
+        Message::File => "Doit être un fichier.".to_string(),
+        Message::Directory => "Doit être un répertoire.".to_string(),
+        Message::Extension(extensions) => {
+            format!("Doit se terminer par : .{}.", extensions.join(", ."))
+        }
+        Message::Readable => "Doit être lisible.".to_string(),
+        Message::Writable => "Doit être accessible en écriture.".to_string(),
+        Message::MinLength(min) => format!(
+            "Doit contenir au moins {} {}.",
+            min,
+            plural(*min, "caractère", "caractères")
+        ),
+        Message::MaxLength(max) => format!(
+            "Doit contenir au plus {} {}.",
+            max,
+            plural(*max, "caractère", "caractères")
+        ),
+        Message::LengthBetween(min, max) => {
+            format!("Doit contenir entre {} et {} caractères.", min, max)
+        }
+        Message::NotEmpty => "Ne doit pas être vide.".to_string(),
+        Message::Ascii => "Ne doit contenir que des caractères ASCII.".to_string(),
+        Message::Alphanumeric => "Ne doit contenir que des lettres et des chiffres.".to_string(),
+        Message::Matches(pattern) => format!("Doit correspondre à `{}`.", pattern),
+        Message::Finite => "Doit être un nombre fini.".to_string(),
+        Message::NotNan => "Doit être un nombre, pas NaN.".to_string(),
+        Message::DecimalPlaces(0) => "Doit être un nombre entier.".to_string(),
+        Message::DecimalPlaces(places) => format!(
+            "Doit avoir au plus {} {}.",
+            places,
+            plural(*places, "décimale", "décimales")
+        ),
+        Message::Step(step) => format!("Doit être un multiple de {}.", step),
+        Message::ApproxNot(this, eps) => {
+            format!("Ne doit pas être à moins de {} de {}.", eps, this)
+        }
+    })
+}
+
+fn spanish(message: &Message) -> Option<String> {
+    Some(match message {
+        Message::AtLeast(min) => format!("Debe ser al menos {}.", min),
+        Message::AtMost(max) => format!("Debe ser como máximo {}.", max),
+        Message::Below(max) => format!("Debe ser menor que {}.", max),
+        Message::Between(min, max) => format!("Debe estar entre {} y {}.", min, max),
+        Message::Range(min, max) => format!("Debe ser al menos {} y menor que {}.", min, max),
+        Message::Not(this) => format!("No debe ser {}.", this),
+        Message::OneOf(values) => format!("Debe ser uno de: {}.", values.join(", ")),
+        Message::Invalid => "Ese valor no es válido. Inténtelo de nuevo".to_string(),
+        Message::DidYouMean(input, suggestion) => format!(
+            "`{}` no es válido — ¿quiso decir `{}`? Escriba y para usarlo.",
+            input, suggestion
+        ),
+        Message::Suggest(suggestion) => {
+            format!("¿Quiso decir `{}`? Escriba y para usarlo.", suggestion)
+        }
+        Message::Prompt(kind) => match kind {
+            Kind::Bool => "Introduzca true o false: ",
+            Kind::Char => "Introduzca un carácter: ",
+            Kind::Integer => "Introduzca un número entero: ",
+            Kind::PositiveInteger => "Introduzca un número entero positivo: ",
+            Kind::Number => "Introduzca un número: ",
+        }
+        .to_string(),
+        Message::KindErr(kind) => match kind {
+            Kind::Bool => "Escriba solo true o false.",
+            Kind::Char => "Escriba un solo carácter.",
+            Kind::Integer => "Escriba solo números enteros.",
+            Kind::PositiveInteger => "Escriba solo números enteros positivos.",
+            Kind::Number => "Escriba solo números o el punto decimal.",
+        }
+        .to_string(),
+        Message::Item(n, input, err) => format!("Elemento {} (`{}`): {}", n, input, err),
+        Message::ItemNotValid(n, input, kind) => {
+            format!("El elemento {} (`{}`) no es un {} válido.", n, input, kind)
+        }
+        Message::TooFewItems(min) => format!(
+            "Debe tener al menos {} {}.",
+            min,
+            plural(*min, "elemento", "elementos")
+        ),
+        Message::TooManyItems(max) => format!(
+            "Debe tener como máximo {} {}.",
+            max,
+            plural(*max, "elemento", "elementos")
+        ),
+        Message::RepeatedItem(n, input) => {
+            format!("El elemento {} (`{}`) está repetido.", n, input)
+        }
+        Message::ItemPrompt(n) => format!("Elemento {}: ", n),
+        Message::ValueCount(expected, given) => format!(
+            "Se esperaban {} valores pero se recibieron {}.",
+            expected, given
+        ),
+        Message::NotValidAt(n, kind) => match ordinal(
+            *n,
+            [
+                "primer", "segundo", "tercer", "cuarto", "quinto", "sexto", "séptimo", "octavo",
+            ],
+        ) {
+            Some(nth) => format!("El {} valor no es un {} válido.", nth, kind),
+            None => format!("El valor {} no es un {} válido.", n, kind),
+        },
+        Message::Pattern(pattern) => format!("Debe tener la forma `{}`.", pattern),
+        Message::InvalidUtf8 => "La entrada no es UTF-8 válido.".to_string(),
+        Message::Exists => "Debe ser una ruta existente.".to_string(),
+        Message::NotExists => "No debe existir todavía.".to_string(),
+        Message::File => "Debe ser un archivo.".to_string(),
+        Message::Directory => "Debe ser un directorio.".to_string(),
+        Message::Extension(extensions) => {
+            format!("Debe terminar en una de: .{}.", extensions.join(", ."))
+        }
+        Message::Readable => "Debe poder leerse.".to_string(),
+        Message::Writable => "Debe poder escribirse.".to_string(),
+        Message::MinLength(min) => format!(
+            "Debe tener al menos {} {}.",
+            min,
+            plural(*min, "carácter", "caracteres")
+        ),
+        Message::MaxLength(max) => format!(
+            "Debe tener como máximo {} {}.",
+            max,
+            plural(*max, "carácter", "caracteres")
+        ),
+        Message::LengthBetween(min, max) => {
+            format!("Debe tener entre {} y {} caracteres.", min, max)
+        }
+        Message::NotEmpty => "No debe estar vacío.".to_string(),
+        Message::Ascii => "Solo debe contener caracteres ASCII.".to_string(),
+        Message::Alphanumeric => "Solo debe contener letras y dígitos.".to_string(),
+        Message::Matches(pattern) => format!("Debe coincidir con `{}`.", pattern),
+        Message::Finite => "Debe ser un número finito.".to_string(),
+        Message::NotNan => "Debe ser un número, no NaN.".to_string(),
+        Message::DecimalPlaces(0) => "Debe ser un número entero.".to_string(),
+        Message::DecimalPlaces(places) => format!(
+            "Debe tener como máximo {} {}.",
+            places,
+            plural(*places, "decimal", "decimales")
+        ),
+        Message::Step(step) => format!("Debe ser un múltiplo de {}.", step),
+        Message::ApproxNot(this, eps) => {
+            format!("No debe estar a menos de {} de {}.", eps, this)
+        }
+    })
+}
@@ -0,0 +1,375 @@
+//! Built-in messages and their translation.
+//!
+//! Every message `read_input` writes by itself is a [`Message`]. This includes the messages
+//! constraints such as [`InputConstraints::min`](crate::InputConstraints::min) describe
+//! themselves with, the fallback error message and the prompts used by
+//! [`input_d`](crate::shortcut::input_d).
+//!
+//! Messages are looked up in the catalogue of the current locale. The locale is taken from the
+//! `LC_ALL`, `LC_MESSAGES` or `LANG` environment variables and can be changed with
+//! [`set_locale`]. Catalogues for English (`en`), German (`de`), French (`fr`) and Spanish (`es`)
+//! are bundled and more can be added with [`register`]. Messages a catalogue does not translate
+//! are written in English.
+//!
+//! ```no_run
+//! use read_input::messages::{self, Message};
+//! # use read_input::prelude::*;
+//! messages::register("nl", |m: &Message| match m {
+//!     Message::Invalid => Some("Die waarde is ongeldig. Probeer het opnieuw".to_string()),
+//!     _ => None,
+//! });
+//! messages::set_locale("nl");
+//! let number: u32 = input().get();
+//! ```
+//!
+//! A single builder can be translated with [`InputBuild::translate`](crate::InputBuild::translate).
+
+use crate::locales;
+use std::{
+    collections::HashMap,
+    env,
+    fmt::{self, Display},
+    sync::{Arc, OnceLock, RwLock},
+};
+
+/// A built-in message with its values already formatted.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Message {
+    /// Value must be greater than or equal to the bound.
+    AtLeast(String),
+    /// Value must be less than or equal to the bound.
+    AtMost(String),
+    /// Value must be less than the bound.
+    Below(String),
+    /// Value must be within the inclusive bounds.
+    Between(String, String),
+    /// Value must be at least the first bound and less than the second.
+    Range(String, String),
+    /// Value must not be equal to this value.
+    Not(String),
+    /// Value must be one of these values.
+    OneOf(Vec<String>),
+    /// Fallback error message used when nothing more specific is known.
+    Invalid,
+    /// Input is not one of the allowed values but is close to the second value.
+    DidYouMean(String, String),
+    /// Suggests a value after a custom error message.
+    Suggest(String),
+    /// Prompt used by [`input_d`](crate::shortcut::input_d).
+    Prompt(Kind),
+    /// Error message used by [`input_d`](crate::shortcut::input_d).
+    KindErr(Kind),
+    /// An item of a list is not valid. Holds its position counting from 1, its text and the
+    /// error message of the item.
+    Item(usize, String, String),
+    /// An item of a list can not be parsed. Holds its position counting from 1, its text and
+    /// the name of its type.
+    ItemNotValid(usize, String, String),
+    /// List must have at least this many items.
+    TooFewItems(usize),
+    /// List must have at most this many items.
+    TooManyItems(usize),
+    /// An item of a list is the same as an earlier one. Holds its position counting from 1
+    /// and its text.
+    RepeatedItem(usize, String),
+    /// Prompt used when collecting values without a prompt message of their own.
+    ItemPrompt(usize),
+    /// Line has the wrong number of values. Holds the expected and the given number.
+    ValueCount(usize, usize),
+    /// A value of a tuple can not be parsed. Holds its position counting from 1 and the name
+    /// of its type.
+    NotValidAt(usize, String),
+    /// Line does not match this pattern.
+    Pattern(String),
+    /// Input is not valid UTF-8.
+    InvalidUtf8,
+    /// Path must exist.
+    Exists,
+    /// Path must not exist.
+    NotExists,
+    /// Path must be a file.
+    File,
+    /// Path must be a directory.
+    Directory,
+    /// Path must have one of these extensions.
+    Extension(Vec<String>),
+    /// Path must be readable.
+    Readable,
+    /// Path must be writable.
+    Writable,
+    /// Text must have at least this many characters.
+    MinLength(usize),
+    /// Text must have at most this many characters.
+    MaxLength(usize),
+    /// Text must have at least the first and at most the second number of characters.
+    LengthBetween(usize, usize),
+    /// Text must not be empty.
+    NotEmpty,
+    /// Text must only contain ASCII characters.
+    Ascii,
+    /// Text must only contain letters and digits.
+    Alphanumeric,
+    /// Text must match this regular expression.
+    Matches(String),
+    /// Number must not be infinite or NaN.
+    Finite,
+    /// Number must not be NaN.
+    NotNan,
+    /// Number must have at most this many digits after the decimal point.
+    DecimalPlaces(usize),
+    /// Number must be a multiple of this step.
+    Step(String),
+    /// Number must differ from the first value by more than the second.
+    ApproxNot(String, String),
+}
+
+/// Kinds of value that have tailored prompts in [`DefaultBuilderSettings`](crate::shortcut::DefaultBuilderSettings).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Kind {
+    /// `true` or `false`.
+    Bool,
+    /// A single character.
+    Char,
+    /// A signed integer.
+    Integer,
+    /// An unsigned integer.
+    PositiveInteger,
+    /// A floating point number.
+    Number,
+}
+
+impl Message {
+    // Lower and upper bound used for the `{min}` and `{max}` placeholders.
+    pub(crate) fn bounds(&self) -> (Option<&str>, Option<&str>) {
+        match self {
+            Self::AtLeast(min) => (Some(min), None),
+            Self::AtMost(max) | Self::Below(max) => (None, Some(max)),
+            Self::Between(min, max) | Self::Range(min, max) => (Some(min), Some(max)),
+            _ => (None, None),
+        }
+    }
+}
+
+impl Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AtLeast(min) => write!(f, "Must be at least {}.", min),
+            Self::AtMost(max) => write!(f, "Must be at most {}.", max),
+            Self::Below(max) => write!(f, "Must be less than {}.", max),
+            Self::Between(min, max) => write!(f, "Must be between {} and {}.", min, max),
+            Self::Range(min, max) => write!(f, "Must be at least {} and less than {}.", min, max),
+            Self::Not(this) => write!(f, "Must not be {}.", this),
+            Self::OneOf(values) => write!(f, "Must be one of: {}.", values.join(", ")),
+            Self::Invalid => write!(f, "That value does not pass. Please try again"),
+            Self::DidYouMean(input, suggestion) => write!(
+                f,
+                "`{}` is not valid — did you mean `{}`? Type y to use it.",
+                input, suggestion
+            ),
+            Self::Suggest(suggestion) => {
+                write!(f, "Did you mean `{}`? Type y to use it.", suggestion)
+            }
+            Self::Prompt(kind) => f.write_str(match kind {
+                Kind::Bool => "Please input true or false: ",
+                Kind::Char => "Please input a character: ",
+                Kind::Integer => "Please input an integer: ",
+                Kind::PositiveInteger => "Please input a positive integer: ",
+                Kind::Number => "Please input a number: ",
+            }),
+            Self::KindErr(kind) => f.write_str(match kind {
+                Kind::Bool => "Only type true or false.",
+                Kind::Char => "Only type a single character.",
+                Kind::Integer => "Only type integers.",
+                Kind::PositiveInteger => "Only type positive integers.",
+                Kind::Number => "Only type numbers or decimal point.",
+            }),
+            Self::Item(n, input, err) => write!(f, "Item {} (`{}`): {}", n, input, err),
+            Self::ItemNotValid(n, input, kind) => {
+                write!(f, "Item {} (`{}`) is not a valid {}.", n, input, kind)
+            }
+            Self::TooFewItems(min) => write!(f, "Must have at least {} {}.", min, items(*min)),
+            Self::TooManyItems(max) => write!(f, "Must have at most {} {}.", max, items(*max)),
+            Self::RepeatedItem(n, input) => {
+                write!(f, "Item {} (`{}`) is given more than once.", n, input)
+            }
+            Self::ItemPrompt(n) => write!(f, "Item {}: ", n),
+            Self::ValueCount(expected, given) => {
+                write!(f, "Expected {} values but got {}.", expected, given)
+            }
+            Self::NotValidAt(n, kind) => match ordinal(*n) {
+                Some(nth) => write!(f, "The {} value is not a valid {}.", nth, kind),
+                None => write!(f, "Value {} is not a valid {}.", n, kind),
+            },
+            Self::Pattern(pattern) => write!(f, "Must look like `{}`.", pattern),
+            Self::InvalidUtf8 => write!(f, "Input is not valid UTF-8."),
+            Self::Exists => write!(f, "Must be an existing path."),
+            Self::NotExists => write!(f, "Must not exist yet."),
+            Self::File => write!(f, "Must be a file."),
+            Self::Directory => write!(f, "Must be a directory."),
+            Self::Extension(extensions) => {
+                write!(f, "Must end in one of: .{}.", extensions.join(", ."))
+            }
+            Self::Readable => write!(f, "Must be readable."),
+            Self::Writable => write!(f, "Must be writable."),
+            Self::MinLength(min) => {
+                write!(f, "Must be at least {} {} long.", min, characters(*min))
+            }
+            Self::MaxLength(max) => {
+                write!(f, "Must be at most {} {} long.", max, characters(*max))
+            }
+            Self::LengthBetween(min, max) => write!(
+                f,
+                "Must be between {} and {} {} long.",
+                min,
+                max,
+                characters(*max)
+            ),
+            Self::NotEmpty => write!(f, "Must not be empty."),
+            Self::Ascii => write!(f, "Must only contain ASCII characters."),
+            Self::Alphanumeric => write!(f, "Must only contain letters and digits."),
+            Self::Matches(pattern) => write!(f, "Must match `{}`.", pattern),
+            Self::Finite => write!(f, "Must be a finite number."),
+            Self::NotNan => write!(f, "Must be a number, not NaN."),
+            Self::DecimalPlaces(places) => match places {
+                0 => write!(f, "Must be a whole number."),
+                1 => write!(f, "Must have at most 1 decimal place."),
+                _ => write!(f, "Must have at most {} decimal places.", places),
+            },
+            Self::Step(step) => write!(f, "Must be a multiple of {}.", step),
+            Self::ApproxNot(this, eps) => write!(f, "Must not be within {} of {}.", eps, this),
+        }
+    }
+}
+
+fn ordinal(n: usize) -> Option<&'static str> {
+    [
+        "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth",
+    ]
+    .get(n.checked_sub(1)?)
+    .copied()
+}
+
+fn items(count: usize) -> &'static str {
+    if count == 1 {
+        "item"
+    } else {
+        "items"
+    }
+}
+
+fn characters(count: usize) -> &'static str {
+    if count == 1 {
+        "character"
+    } else {
+        "characters"
+    }
+}
+
+/// Trait for translating built-in messages.
+///
+/// Return `None` to fall back to the English message.
+/// It is implemented for closures.
+///
+/// ```no_run
+/// # use read_input::prelude::*;
+/// use read_input::messages::Message;
+/// let age: u8 = input()
+///     .min(18)
+///     .translate(|m: &Message| match m {
+///         Message::AtLeast(min) => Some(format!("Mindestens {}.", min)),
+///         _ => None,
+///     })
+///     .get();
+/// ```
+pub trait Translate {
+    /// Returns the translated text of `message`.
+    fn translate(&self, message: &Message) -> Option<String>;
+}
+
+impl<F: Fn(&Message) -> Option<String>> Translate for F {
+    fn translate(&self, message: &Message) -> Option<String> {
+        self(message)
+    }
+}
+
+type SharedTranslate = Arc<dyn Translate + Send + Sync>;
+
+struct Catalogue {
+    locale: String,
+    registered: HashMap<String, SharedTranslate>,
+}
+
+impl Catalogue {
+    // Registered or bundled catalogue for a locale such as `de_AT.UTF-8`.
+    // Falls back from the full locale to the language alone.
+    fn find(&self, locale: &str) -> Option<SharedTranslate> {
+        let full = locale.split(['.', '@']).next().unwrap_or("");
+        let language = full.split(['_', '-']).next().unwrap_or("");
+        [full, language].iter().find_map(|code| {
+            self.registered
+                .get(*code)
+                .cloned()
+                .or_else(|| locales::bundled(code))
+        })
+    }
+}
+
+fn catalogue() -> &'static RwLock<Catalogue> {
+    static CATALOGUE: OnceLock<RwLock<Catalogue>> = OnceLock::new();
+    CATALOGUE.get_or_init(|| {
+        let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .unwrap_or_else(|| "en".to_string());
+        RwLock::new(Catalogue {
+            locale,
+            registered: HashMap::new(),
+        })
+    })
+}
+
+/// Changes the locale used for built-in messages.
+///
+/// Locales can be given as a language (`de`) or with a region (`de_AT`, `de-AT.UTF-8`).
+/// Returns `false` and keeps the current locale if there is no catalogue for `locale`.
+pub fn set_locale(locale: &str) -> bool {
+    let mut catalogue = catalogue().write().unwrap_or_else(|e| e.into_inner());
+    let found = catalogue.find(locale).is_some();
+    if found {
+        catalogue.locale = locale.to_string();
+    }
+    found
+}
+
+/// Returns the locale used for built-in messages.
+pub fn locale() -> String {
+    catalogue()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .locale
+        .clone()
+}
+
+/// Adds a catalogue for a locale or replaces a bundled one.
+pub fn register(locale: &str, translate: impl Translate + Send + Sync + 'static) {
+    catalogue()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .registered
+        .insert(locale.to_string(), Arc::new(translate));
+}
+
+/// Returns the text of `message` in the current locale.
+pub fn text(message: &Message) -> String {
+    // The lock is released before translating so translators can use this module themselves.
+    let translate = {
+        let catalogue = catalogue().read().unwrap_or_else(|e| e.into_inner());
+        catalogue.find(&catalogue.locale)
+    };
+    translate
+        .and_then(|translate| translate.translate(message))
+        .unwrap_or_else(|| message.to_string())
+}
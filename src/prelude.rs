@@ -1,3 +1,8 @@
 //! Module to be imported in the style `use read_input::prelude::*;` to get the essential functions and traits.
 
-pub use crate::{shortcut::input, InputBuild, InputConstraints};
+pub use crate::{
+    constraints::{FloatConstraints, PathConstraints, StringConstraints},
+    input, scan,
+    shortcut::{input, input_list, input_os_string, input_path, input_tuple},
+    InputBuild, InputConstraints,
+};
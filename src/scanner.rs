@@ -0,0 +1,207 @@
+//! Fast reading of many whitespace separated values.
+//!
+//! Builders prompt and validate one line at a time. When reading large amounts of piped data,
+//! such as millions of numbers, a [`Scanner`] is much faster. It reads from a locked and
+//! buffered stdin or any other [`BufRead`], reuses its line buffer and parses tokens in place.
+//!
+//! ```no_run
+//! use read_input::scanner::Scanner;
+//! let mut scanner = Scanner::stdin();
+//! let count: usize = scanner.token().unwrap();
+//! let numbers: Vec<i64> = scanner.tokens(count).unwrap();
+//! ```
+
+use crate::tuple::short_type_name;
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::{self, BufRead, StdinLock},
+    str::FromStr,
+};
+
+/// Reads whitespace separated tokens and parses them with [`FromStr`].
+pub struct Scanner<R> {
+    reader: R,
+    line: String,
+    position: usize,
+    line_number: usize,
+}
+
+impl Scanner<StdinLock<'static>> {
+    /// Creates a scanner that reads from stdin.
+    ///
+    /// Stdin stays locked until the scanner is dropped.
+    pub fn stdin() -> Self {
+        Self::new(io::stdin().lock())
+    }
+}
+
+impl<R: BufRead> Scanner<R> {
+    /// Creates a scanner that reads from `reader`.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            position: 0,
+            line_number: 0,
+        }
+    }
+    /// Reads the next token and parses it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if there are no tokens left, reading fails or the token can not be parsed.
+    pub fn token<T: FromStr>(&mut self) -> Result<T, ScanError> {
+        let (start, end) = self.next_token()?;
+        self.line[start..end].parse().map_err(|_| {
+            self.error(
+                start,
+                ScanErrorKind::Invalid {
+                    token: self.line[start..end].to_string(),
+                    type_name: short_type_name::<T>(),
+                },
+            )
+        })
+    }
+    /// Reads the next `count` tokens and parses them.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of [`Scanner::token`].
+    pub fn tokens<T: FromStr>(&mut self, count: usize) -> Result<Vec<T>, ScanError> {
+        (0..count).map(|_| self.token()).collect()
+    }
+    /// Returns `true` if there are no tokens left.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if reading fails.
+    pub fn is_done(&mut self) -> Result<bool, ScanError> {
+        match self.skip_whitespace() {
+            Ok(()) => Ok(false),
+            Err(e) if matches!(e.kind, ScanErrorKind::UnexpectedEof) => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
+    /// Number of the line that is being read, counting from 1.
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+    // Moves to the start of the next token, reading lines as needed.
+    fn skip_whitespace(&mut self) -> Result<(), ScanError> {
+        loop {
+            let rest = &self.line[self.position..];
+            let trimmed = rest.trim_start();
+            self.position += rest.len() - trimmed.len();
+            if !trimmed.is_empty() {
+                return Ok(());
+            }
+            self.line.clear();
+            self.position = 0;
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => {
+                    return Err(ScanError {
+                        line: self.line_number + 1,
+                        column: 1,
+                        kind: ScanErrorKind::UnexpectedEof,
+                    })
+                }
+                Ok(_) => self.line_number += 1,
+                Err(e) => {
+                    self.line_number += 1;
+                    return Err(self.error(0, ScanErrorKind::Io(e)));
+                }
+            }
+        }
+    }
+    // Byte range of the next token in `line`.
+    fn next_token(&mut self) -> Result<(usize, usize), ScanError> {
+        self.skip_whitespace()?;
+        let start = self.position;
+        let len = self.line[start..]
+            .find(char::is_whitespace)
+            .unwrap_or(self.line.len() - start);
+        self.position = start + len;
+        Ok((start, self.position))
+    }
+    fn error(&self, start: usize, kind: ScanErrorKind) -> ScanError {
+        ScanError {
+            line: self.line_number,
+            column: self.line[..start].chars().count() + 1,
+            kind,
+        }
+    }
+}
+
+/// Error returned by a [`Scanner`], with the position where it happened.
+#[derive(Debug)]
+pub struct ScanError {
+    line: usize,
+    column: usize,
+    kind: ScanErrorKind,
+}
+
+/// What went wrong when scanning.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ScanErrorKind {
+    /// Reading from the underlying reader failed.
+    Io(io::Error),
+    /// Input ended before the token was found.
+    UnexpectedEof,
+    /// The token could not be parsed as the requested type.
+    Invalid {
+        /// Text of the token.
+        token: String,
+        /// Name of the requested type.
+        type_name: String,
+    },
+}
+
+impl ScanError {
+    /// Line of the error, counting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+    /// Column of the error in characters, counting from 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+    /// What went wrong.
+    pub fn kind(&self) -> &ScanErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.kind {
+            ScanErrorKind::Io(e) => write!(f, "{}", e),
+            ScanErrorKind::UnexpectedEof => write!(f, "unexpected end of input"),
+            ScanErrorKind::Invalid { token, type_name } => {
+                write!(f, "`{}` is not a valid {}", token, type_name)
+            }
+        }
+    }
+}
+
+impl Error for ScanError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ScanErrorKind::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ScanError> for io::Error {
+    fn from(error: ScanError) -> Self {
+        let kind = match &error.kind {
+            ScanErrorKind::Io(e) => e.kind(),
+            ScanErrorKind::UnexpectedEof => io::ErrorKind::UnexpectedEof,
+            ScanErrorKind::Invalid { .. } => io::ErrorKind::InvalidData,
+        };
+        io::Error::new(kind, error)
+    }
+}
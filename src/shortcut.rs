@@ -2,8 +2,14 @@
 //!
 //! Using `input().get()` can be a little verbose in simple situations.
 
-use crate::{test_generators::InsideFunc, InputBuild, InputBuilder};
-use std::{error::Error, fmt::Display, str::FromStr};
+use crate::{
+    list::ListBuilder,
+    messages::{text, Kind, Message},
+    test_generators::InsideFunc,
+    tuple::{Tuple, TupleBuilder},
+    InputBuild, InputBuilder,
+};
+use std::{error::Error, ffi::OsString, fmt::Display, path::PathBuf, str::FromStr};
 
 /// Shortcut function. Fetches input that is validated with a test function.
 ///
@@ -39,30 +45,145 @@ pub fn input<T: FromStr>() -> InputBuilder<T> {
     InputBuilder::new()
 }
 
-/// [input_d] works like [input] but uses the default input settings that are specified by the [DefaultBuilderSettings] trait.
+/// Creates a new instance of [`ListBuilder`] that reads a list of values from one line.
+///
+/// Items are separated by commas unless [`ListBuilder::delimiter`] is used.
+///
+/// ```no_run
+/// # use read_input::prelude::*;
+/// let numbers: Vec<i32> = input_list().msg("Numbers: ").get();
+/// ```
+pub fn input_list<T: FromStr>() -> ListBuilder<T> {
+    ListBuilder::new()
+}
+
+/// Creates a new instance of [`TupleBuilder`] that reads a tuple of values from one line.
+///
+/// Values are separated by whitespace unless [`TupleBuilder::separator`] is used.
+///
+/// ```no_run
+/// # use read_input::prelude::*;
+/// let (name, age): (String, u8) = input_tuple().msg("Name and age: ").get();
+/// ```
+pub fn input_tuple<T: Tuple>() -> TupleBuilder<T> {
+    TupleBuilder::new()
+}
+
+/// Fetches input with the common settings given in one call.
+///
+/// Arguments are an optional prompt message, the type, and then any number of constraints for
+/// [`InputBuild::inside`] and `setting = value` pairs, which call the builder method of the same
+/// name. Use `_` as the type to have it inferred. Without a type only `setting = value` pairs can
+/// follow the prompt message.
+///
+/// ```no_run
+/// # use read_input::prelude::*;
+/// let name: String = input!("Name: ");
+/// let city: String = input!("City: ", initial_text = "Paris");
+/// let age = input!("Age: ", u8, 0..=130, default = 18);
+/// let colour = input!(String, ["red".to_string(), "blue".to_string()], err = "No such colour.");
+/// let year: i32 = input!("Year: ", _, min = 1900, max_attempts = 3);
+/// ```
+///
+/// `input!("Age: ", u8, 0..=130, default = 18)` is the same as
+/// `input::<u8>().msg("Age: ").inside(0..=130).default(18).get()`.
+#[macro_export]
+macro_rules! input {
+    (@chain $builder:expr;) => {
+        $builder.get()
+    };
+    (@chain $builder:expr; $setting:ident = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::input!(@chain $builder.$setting($value); $($($rest)*)?)
+    };
+    (@chain $builder:expr; $constraint:expr $(, $($rest:tt)*)?) => {
+        $crate::input!(@chain $builder.inside($constraint); $($($rest)*)?)
+    };
+    ($($setting:ident = $value:expr),* $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::{InputBuild as _, InputConstraints as _};
+        $crate::input!(@chain $crate::shortcut::input(); $($setting = $value),*)
+    }};
+    ($msg:literal $(, $setting:ident = $value:expr)* $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::{InputBuild as _, InputConstraints as _};
+        $crate::input!(@chain $crate::shortcut::input().msg($msg); $($setting = $value),*)
+    }};
+    ($msg:literal, $type:ty $(, $($rest:tt)*)?) => {{
+        #[allow(unused_imports)]
+        use $crate::{InputBuild as _, InputConstraints as _};
+        $crate::input!(@chain $crate::shortcut::input::<$type>().msg($msg); $($($rest)*)?)
+    }};
+    ($type:ty $(, $($rest:tt)*)?) => {{
+        #[allow(unused_imports)]
+        use $crate::{InputBuild as _, InputConstraints as _};
+        $crate::input!(@chain $crate::shortcut::input::<$type>(); $($($rest)*)?)
+    }};
+}
+
+// Keeps the bytes of lines that are not valid UTF-8.
+#[cfg(unix)]
+fn os_string(bytes: &[u8]) -> OsString {
+    use std::os::unix::ffi::OsStrExt;
+    std::ffi::OsStr::from_bytes(bytes).to_os_string()
+}
+
+/// Creates a new instance of [`InputBuilder`] for an [`OsString`].
+///
+/// On Unix lines that are not valid UTF-8 are kept as they are. Elsewhere invalid bytes are
+/// replaced with `�`.
+pub fn input_os_string() -> InputBuilder<OsString> {
+    #[cfg(unix)]
+    return input().keep_bytes(os_string);
+    #[cfg(not(unix))]
+    return input().lossy_utf8();
+}
+
+/// Creates a new instance of [`InputBuilder`] for a [`PathBuf`].
+///
+/// On Unix paths that are not valid UTF-8 are kept as they are. Elsewhere invalid bytes are
+/// replaced with `�`.
+///
+/// ```no_run
+/// # use read_input::prelude::*;
+/// let path = input_path().msg("File: ").get();
+/// ```
+pub fn input_path() -> InputBuilder<PathBuf> {
+    #[cfg(unix)]
+    return input().keep_bytes(|bytes| PathBuf::from(os_string(bytes)));
+    #[cfg(not(unix))]
+    return input().lossy_utf8();
+}
+
+/// [input_d] works like [input()] but uses the default input settings that are specified by the [DefaultBuilderSettings] trait.
 pub fn input_d<T: DefaultBuilderSettings>() -> InputBuilder<T> {
     T::settings()
 }
 
 /// Trait for describing specifically tailored input settings for types.
+///
+/// The prompts and error messages of the implementations for standard library types are taken
+/// from the catalogue of the current locale. See the [`messages`](crate::messages) module.
 pub trait DefaultBuilderSettings: FromStr {
     /// Returns tailored `InputBuilder`.
     fn settings() -> InputBuilder<Self>;
 }
 
+// Builder using the prompt and error message for a kind of value.
+fn input_kind<T: FromStr>(kind: Kind) -> InputBuilder<T> {
+    input()
+        .repeat_msg(text(&Message::Prompt(kind)))
+        .err(text(&Message::KindErr(kind)))
+}
+
 impl DefaultBuilderSettings for bool {
     fn settings() -> InputBuilder<Self> {
-        input()
-            .repeat_msg("Please input true or false: ")
-            .err("Only type true or false.")
+        input_kind(Kind::Bool)
     }
 }
 
 impl DefaultBuilderSettings for char {
     fn settings() -> InputBuilder<Self> {
-        input()
-            .repeat_msg("Please input a character: ")
-            .err("Only type a single character.")
+        input_kind(Kind::Char)
     }
 }
 
@@ -70,9 +191,7 @@ macro_rules! impl_default_builder_for_int {
     ($($t:ty),*) => {$(
     impl DefaultBuilderSettings for $t {
         fn settings() -> InputBuilder<Self> {
-            input()
-                .repeat_msg("Please input an integer: ")
-                .err("Only type integers.")
+            input_kind(Kind::Integer)
         }
     }
     )*}
@@ -84,9 +203,7 @@ macro_rules! impl_default_builder_for_whole {
     ($($t:ty),*) => {$(
     impl DefaultBuilderSettings for $t {
         fn settings() -> InputBuilder<Self> {
-            input()
-                .repeat_msg("Please input a positive integer: ")
-                .err("Only type positive integers.")
+            input_kind(Kind::PositiveInteger)
         }
     }
     )*}
@@ -98,9 +215,7 @@ macro_rules! impl_default_builder_for_float {
     ($($t:ty),*) => {$(
     impl DefaultBuilderSettings for $t {
         fn settings() -> InputBuilder<Self> {
-            input()
-                .repeat_msg("Please input a number: ")
-                .err("Only type numbers or decimal point.")
+            input_kind(Kind::Number)
         }
     }
     )*}
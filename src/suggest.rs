@@ -0,0 +1,54 @@
+// Fuzzy matching used to suggest a value when input is not one of the allowed choices.
+
+// Finds the candidate the user most likely meant to type.
+pub(crate) fn closest<'a>(input: &str, candidates: &'a [String]) -> Option<&'a String> {
+    let input = input.to_lowercase();
+    let lower: Vec<String> = candidates.iter().map(|x| x.to_lowercase()).collect();
+
+    if let Some(i) = lower.iter().position(|x| *x == input) {
+        return Some(&candidates[i]);
+    }
+
+    let mut prefixed = lower
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| x.starts_with(&input));
+    if let (Some((i, _)), None) = (prefixed.next(), prefixed.next()) {
+        return Some(&candidates[i]);
+    }
+
+    let max = input.chars().count() / 3;
+    lower
+        .iter()
+        .enumerate()
+        .map(|(i, x)| (distance(&input, x), i))
+        .filter(|(d, _)| *d <= max)
+        .min()
+        .map(|(_, i)| &candidates[i])
+}
+
+// Edit distance where swapping two neighbouring characters counts as one edit.
+pub(crate) fn distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
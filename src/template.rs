@@ -0,0 +1,30 @@
+// Filling `{name}` placeholders in error messages.
+
+// Replaces each `{name}` with `lookup(name)`. Unknown names are left as they are.
+// `{{` and `}}` are written as `{` and `}`.
+pub(crate) fn render(template: &str, lookup: impl Fn(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut chars = template.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let doubled = chars.peek().map(|&(_, next)| next) == Some(c);
+        match c {
+            '{' | '}' if doubled => {
+                chars.next();
+                out.push(c);
+            }
+            '{' => match template[i + 1..].find('}') {
+                Some(len) => {
+                    let end = i + 1 + len;
+                    match lookup(&template[i + 1..end]) {
+                        Some(value) => out.push_str(&value),
+                        None => out.push_str(&template[i..=end]),
+                    }
+                    while chars.next_if(|&(j, _)| j <= end).is_some() {}
+                }
+                None => out.push(c),
+            },
+            _ => out.push(c),
+        }
+    }
+    out
+}
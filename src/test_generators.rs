@@ -1,5 +1,7 @@
+use crate::messages::Message;
 use std::{
     cmp::PartialOrd,
+    fmt::Display,
     ops::{
         Bound::{Excluded, Included, Unbounded},
         Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
@@ -11,6 +13,26 @@ use std::{
 pub trait InsideFunc<T> {
     /// Returns constraint as a function.
     fn contains_func(self) -> Rc<dyn Fn(&T) -> bool>;
+    /// Returns every allowed value if the constraint is a list of values.
+    ///
+    /// These are used for tab completion.
+    fn candidates(&self) -> Option<Vec<String>> {
+        None
+    }
+    /// Returns a description of the constraint used as its error message.
+    fn message(&self) -> Option<Message> {
+        self.candidates().map(Message::OneOf)
+    }
+    /// Returns a function that displays values of the constrained type.
+    ///
+    /// It is used for the `{value}` placeholder in error messages.
+    fn display_func(&self) -> Option<fn(&T) -> String> {
+        None
+    }
+}
+
+fn display<T: Display>(x: &T) -> String {
+    x.to_string()
 }
 
 impl<T: PartialEq + 'static> InsideFunc<T> for Vec<T> {
@@ -35,6 +57,47 @@ impl_inside_func_for_arrays! {
     29, 30, 31, 32
 }
 
+// Constraint built by `InputBuild::select`, whose values are shown to the user.
+pub(crate) struct Selection<T>(pub Vec<T>);
+
+impl<T: PartialEq + Display + 'static> InsideFunc<T> for Selection<T> {
+    fn contains_func(self) -> Rc<dyn Fn(&T) -> bool> {
+        Rc::new(move |x| self.0.contains(x))
+    }
+    fn candidates(&self) -> Option<Vec<String>> {
+        Some(self.0.iter().map(ToString::to_string).collect())
+    }
+    fn display_func(&self) -> Option<fn(&T) -> String> {
+        Some(display)
+    }
+}
+
+// Constraint that rejects a single value.
+pub(crate) struct NotEqual<T>(pub T);
+
+impl<T: PartialEq + Display + 'static> InsideFunc<T> for NotEqual<T> {
+    fn contains_func(self) -> Rc<dyn Fn(&T) -> bool> {
+        Rc::new(move |x| *x != self.0)
+    }
+    fn message(&self) -> Option<Message> {
+        Some(Message::Not(self.0.to_string()))
+    }
+    fn display_func(&self) -> Option<fn(&T) -> String> {
+        Some(display)
+    }
+}
+
+fn range_message<T: Display>(range: &impl RangeBounds<T>) -> Option<Message> {
+    Some(match (range.start_bound(), range.end_bound()) {
+        (Included(start), Unbounded) => Message::AtLeast(start.to_string()),
+        (Unbounded, Included(end)) => Message::AtMost(end.to_string()),
+        (Unbounded, Excluded(end)) => Message::Below(end.to_string()),
+        (Included(start), Included(end)) => Message::Between(start.to_string(), end.to_string()),
+        (Included(start), Excluded(end)) => Message::Range(start.to_string(), end.to_string()),
+        _ => return None,
+    })
+}
+
 fn range_contains_func<T, U>(range: U) -> Rc<dyn Fn(&T) -> bool>
 where
     T: PartialOrd,
@@ -55,14 +118,26 @@ where
 
 macro_rules! impl_inside_func_for_ranges {
     ($($t:ty),*) => {$(
-        impl<T: PartialOrd + 'static> InsideFunc<T> for $t {
+        impl<T: PartialOrd + Display + 'static> InsideFunc<T> for $t {
             fn contains_func(self) -> Rc<dyn Fn(&T) -> bool> {
                 range_contains_func(self)
             }
+            fn message(&self) -> Option<Message> {
+                range_message(self)
+            }
+            fn display_func(&self) -> Option<fn(&T) -> String> {
+                Some(display)
+            }
         }
     )*}
 }
 
 impl_inside_func_for_ranges! {
-    Range<T>, RangeInclusive<T>, RangeFrom<T>, RangeTo<T>, RangeToInclusive<T>, RangeFull
+    Range<T>, RangeInclusive<T>, RangeFrom<T>, RangeTo<T>, RangeToInclusive<T>
+}
+
+impl<T: PartialOrd + 'static> InsideFunc<T> for RangeFull {
+    fn contains_func(self) -> Rc<dyn Fn(&T) -> bool> {
+        range_contains_func(self)
+    }
 }
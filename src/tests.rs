@@ -1,8 +1,62 @@
-use crate::{core::parse_input, shortcut::input, InputBuild, InputBuilder};
-use std::str::FromStr;
+use crate::{
+    constraints::{expand_with, FloatConstraints, PathConstraints, StringConstraints},
+    core::{completions, parse_input, parse_line},
+    editor::edit,
+    env_default,
+    error::InputError,
+    history::History,
+    iter::OnInvalid,
+    list::Delimiter,
+    locales::bundled,
+    messages::{self, Kind, Message},
+    scanner::{ScanErrorKind, Scanner},
+    shortcut::{input, input_list, input_os_string, input_path, input_tuple},
+    theme::{Colour, Style, Theme, When},
+    DefaultValue, InputBuild, InputBuilder, InputBuilderOnce, InputConstraints,
+};
+use std::{
+    cell::{Cell, RefCell},
+    env, fs,
+    io::{self, BufRead, Cursor, Write},
+    path::PathBuf,
+    rc::Rc,
+    str::FromStr,
+};
+
+// Built-in messages are compared in English whatever the locale of the environment is.
+fn english() {
+    assert!(messages::set_locale("en"));
+}
+
+// Input read from `text` instead of stdin.
+fn reader(text: &str) -> RefCell<Box<dyn BufRead>> {
+    RefCell::new(Box::new(Cursor::new(text.as_bytes().to_vec())))
+}
+
+// Output that can still be read after the builder writing to it is gone.
+#[derive(Clone, Default)]
+struct Output(Rc<RefCell<Vec<u8>>>);
+
+impl Output {
+    fn writer(&self) -> RefCell<Box<dyn Write>> {
+        RefCell::new(Box::new(self.clone()))
+    }
+    fn text(&self) -> String {
+        String::from_utf8(self.0.borrow().clone()).unwrap()
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
 
 fn parse_with_builder<T: FromStr>(builder: InputBuilder<T>, input: String) -> Result<T, String> {
-    parse_input(input, &builder.err, &builder.tests, &*builder.err_match)
+    parse_input(&input, &builder, 1).map_err(|e| e.msg)
 }
 
 #[test]
@@ -132,3 +186,1016 @@ fn test_array() {
         Err("1".to_string())
     );
 }
+
+#[test]
+fn test_history() {
+    let path = env::temp_dir().join(format!("read_input_history_{}", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let history = History::at(&path).limit(3).show_recent(2);
+
+    for entry in &["a", "b", "a", "c", "d"] {
+        history.push(entry).unwrap();
+    }
+    assert_eq!(history.entries(), vec!["a", "c", "d"]);
+
+    assert_eq!(history.recall("\u{1b}[A\n"), Some("d".to_string()));
+    assert_eq!(history.recall("\u{1b}[A\u{1b}[A\n"), Some("c".to_string()));
+    assert_eq!(history.recall("!2"), Some("c".to_string()));
+    assert_eq!(history.recall("!3"), None);
+    assert_eq!(history.recall("a"), None);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_completions() {
+    let builder = input::<String>()
+        .select(vec![
+            "staging".to_string(),
+            "stable".to_string(),
+            "prod".to_string(),
+        ])
+        .completer(|x: &str| vec![format!("{}-custom", x)]);
+    assert_eq!(
+        completions(&builder, "sta"),
+        vec!["sta-custom", "staging", "stable"]
+    );
+    assert_eq!(completions(&builder, "p"), vec!["p-custom", "prod"]);
+    assert_eq!(completions(&builder.clear_tests(), "p"), vec!["p-custom"]);
+    let listed = input::<String>().inside(vec!["prod".to_string()]);
+    assert!(completions(&listed, "p").is_empty());
+
+    let prompts = Output::default();
+    let value = input::<String>()
+        .select(vec!["staging".to_string(), "prod".to_string()])
+        .prompting_on(prompts.writer())
+        .reading_from(reader("pr\t\n"))
+        .batch(false)
+        .get();
+    assert_eq!(value, "prod");
+    assert!(prompts.text().contains("prod"));
+}
+
+#[test]
+fn test_inside_without_display() {
+    #[derive(Debug, PartialEq)]
+    enum Env {
+        Dev,
+        Prod,
+    }
+    impl FromStr for Env {
+        type Err = ();
+        fn from_str(s: &str) -> Result<Self, ()> {
+            match s {
+                "dev" => Ok(Env::Dev),
+                "prod" => Ok(Env::Prod),
+                _ => Err(()),
+            }
+        }
+    }
+    let env = || input::<Env>().inside(vec![Env::Dev]);
+    assert_eq!(parse_with_builder(env(), "dev".to_string()), Ok(Env::Dev));
+    assert!(parse_with_builder(env(), "prod".to_string()).is_err());
+    assert!(parse_with_builder(input().inside([Env::Prod]), "prod".to_string()).is_ok());
+}
+
+#[test]
+fn test_editor() {
+    let type_keys = |keys: &str| {
+        let mut output = Vec::new();
+        let mut line = Vec::new();
+        let result = edit(
+            &mut Cursor::new(keys.as_bytes().to_vec()),
+            &mut output,
+            "Intro\n> ",
+            &mut line,
+            |x| {
+                ["staging", "stable", "prod"]
+                    .iter()
+                    .filter(|c| c.starts_with(x))
+                    .map(|c| c.to_string())
+                    .collect()
+            },
+            |list| format!("({})", list),
+        );
+        result.map(|_| {
+            (
+                String::from_utf8(line).unwrap(),
+                String::from_utf8(output).unwrap(),
+            )
+        })
+    };
+    let (line, output) = type_keys("st\tg\t\n").unwrap();
+    assert_eq!(line, "staging\n");
+    assert_eq!(output, "st\r> sta\x1b[Kg\r> staging\x1b[K\n");
+    let (line, output) = type_keys(" sta\t\n").unwrap();
+    assert_eq!(line, " sta\n");
+    assert_eq!(output, " sta\n(staging  stable)\n>  sta\n");
+    assert_eq!(
+        type_keys("x\t").unwrap(),
+        ("x".to_string(), "x\x07".to_string())
+    );
+    assert_eq!(type_keys("né\x7f\x7fo\n").unwrap().0, "o\n");
+    assert_eq!(type_keys("\x1b[Ap\x1bOD\n").unwrap().0, "p\n");
+    assert_eq!(type_keys("\x04").unwrap().0, "");
+    assert_eq!(type_keys("a\x04\n").unwrap().0, "a\n");
+    assert_eq!(
+        type_keys("a\x03").unwrap_err().kind(),
+        io::ErrorKind::Interrupted
+    );
+}
+
+#[test]
+fn test_suggestion() {
+    english();
+    let envs = || {
+        input::<String>().select(vec![
+            "staging".to_string(),
+            "production".to_string(),
+            "development".to_string(),
+        ])
+    };
+    assert_eq!(
+        parse_with_builder(envs(), "stagign".to_string()),
+        Err("`stagign` is not valid — did you mean `staging`? Type y to use it.".to_string())
+    );
+    assert_eq!(
+        parse_with_builder(envs().err("Unknown."), "Prod".to_string()),
+        Err("Unknown. Did you mean `production`? Type y to use it.".to_string())
+    );
+    assert_eq!(
+        parse_with_builder(envs().err("Unknown."), "qa".to_string()),
+        Err("Unknown.".to_string())
+    );
+
+    let answer = |allowed: Vec<&str>| {
+        input::<String>()
+            .select(allowed.into_iter().map(str::to_string))
+            .reading_from(reader("yess\ny\n"))
+            .prompting_on(Output::default().writer())
+            .errors_on(Output::default().writer())
+            .batch(false)
+            .get()
+    };
+    assert_eq!(answer(vec!["yes", "no"]), "yes");
+    assert_eq!(answer(vec!["y", "n", "yes"]), "y");
+}
+
+#[test]
+fn test_constraint_messages() {
+    english();
+    assert_eq!(
+        parse_with_builder(input().min(4), "3".to_string()),
+        Err("Must be at least 4.".to_string())
+    );
+    assert_eq!(
+        parse_with_builder(input().min_max(4, 9), "10".to_string()),
+        Err("Must be between 4 and 9.".to_string())
+    );
+    assert_eq!(
+        parse_with_builder(input().inside(..6), "6".to_string()),
+        Err("Must be less than 6.".to_string())
+    );
+    assert_eq!(
+        parse_with_builder(input().not(6), "6".to_string()),
+        Err("Must not be 6.".to_string())
+    );
+    assert_eq!(
+        parse_with_builder(
+            input::<String>().select(["red".to_string(), "blue".to_string()]),
+            "x".to_string()
+        ),
+        Err("Must be one of: red, blue.".to_string())
+    );
+    assert_eq!(
+        parse_with_builder(
+            input::<String>().inside(["red".to_string(), "blue".to_string()]),
+            "x".to_string()
+        ),
+        Err("That value does not pass. Please try again".to_string())
+    );
+    assert_eq!(
+        parse_with_builder(input().min_err(4, "1"), "3".to_string()),
+        Err("1".to_string())
+    );
+    assert_eq!(
+        parse_with_builder(
+            input().max(4).translate(|m: &Message| match m {
+                Message::AtMost(max) => Some(format!("Höchstens {}.", max)),
+                _ => None,
+            }),
+            "5".to_string()
+        ),
+        Err("Höchstens 4.".to_string())
+    );
+}
+
+#[test]
+fn test_err_template() {
+    assert_eq!(
+        parse_with_builder(
+            input::<u8>()
+                .inside_err(
+                    4..=9,
+                    "'{input}' is outside {min}..={max} ({remaining} tries left)"
+                )
+                .max_attempts(3),
+            " 010 ".to_string()
+        ),
+        Err("'010' is outside 4..=9 (2 tries left)".to_string())
+    );
+    assert_eq!(
+        parse_with_builder(
+            input::<u8>()
+                .min(4)
+                .err("{value} is too small, {{min}} is {min}"),
+            "03".to_string()
+        ),
+        Err("3 is too small, {min} is 4".to_string())
+    );
+    assert_eq!(
+        parse_with_builder(
+            input::<u8>()
+                .min(4)
+                .add_err_test(|x| x % 2 == 0, "[{min}] {value} is odd"),
+            "5".to_string()
+        ),
+        Err("[] 5 is odd".to_string())
+    );
+    assert_eq!(
+        parse_with_builder(
+            input::<u8>()
+                .min(4)
+                .err("'{input}' is not at least {min}{max}"),
+            "x".to_string()
+        ),
+        Err("'x' is not at least ".to_string())
+    );
+    assert_eq!(
+        parse_with_builder(
+            input::<u8>().err("'{input}' on attempt {attempt} {unknown}"),
+            "x".to_string()
+        ),
+        Err("'x' on attempt 1 {unknown}".to_string())
+    );
+}
+
+#[test]
+fn test_bundled_locales() {
+    let german = bundled("de").unwrap();
+    assert_eq!(
+        german.translate(&Message::Between("4".to_string(), "9".to_string())),
+        Some("Muss zwischen 4 und 9 liegen.".to_string())
+    );
+    assert_eq!(
+        bundled("fr")
+            .unwrap()
+            .translate(&Message::Prompt(Kind::Integer)),
+        Some("Veuillez saisir un entier : ".to_string())
+    );
+    assert_eq!(bundled("en").unwrap().translate(&Message::Invalid), None);
+    assert!(bundled("xx").is_none());
+}
+
+#[test]
+fn test_theme() {
+    let plain = Theme::plain();
+    assert_eq!(plain.paint_prompt("Name: ", None, true), "Name: ");
+    assert_eq!(plain.paint_error("Bad.", true), "Bad.");
+    assert_eq!(plain.paint_success("x", true), None);
+
+    let colourful = Theme::colourful();
+    assert_eq!(colourful.paint_prompt("Name: ", None, false), "? Name: ");
+    assert_eq!(colourful.paint_error("Bad.", false), "✖ Bad.");
+    assert_eq!(
+        colourful.paint_error("Bad.", true),
+        "\u{1b}[31m✖ Bad.\u{1b}[0m"
+    );
+    assert_eq!(
+        colourful.clone().ascii().paint_success("x", false),
+        Some("> x".to_string())
+    );
+    assert_eq!(
+        Theme::plain()
+            .prompt(Style::new().fg(Colour::Blue).bold())
+            .paint_prompt("Name: ", None, true),
+        "\u{1b}[1;34mName: \u{1b}[0m"
+    );
+
+    assert!(!colourful.use_colour(false));
+    assert!(colourful.clone().colour(When::Always).use_colour(false));
+    assert!(!colourful.colour(When::Never).use_colour(true));
+}
+
+#[test]
+fn test_errors_on() {
+    english();
+    let prompts = Output::default();
+    let errors = Output::default();
+    let number = input::<u32>()
+        .repeat_msg("Number: ")
+        .min(3)
+        .batch(false)
+        .reading_from(reader("x\n2\n4\n"))
+        .prompting_on(prompts.writer())
+        .errors_on(errors.writer());
+    assert_eq!(number.try_get().unwrap(), 4);
+    assert_eq!(prompts.text(), "Number: Number: Number: ");
+    assert_eq!(
+        errors.text(),
+        "That value does not pass. Please try again\nMust be at least 3.\n"
+    );
+
+    let clone = number
+        .clone()
+        .reading_from(reader("1\n5\n"))
+        .prompting_on(Output::default().writer());
+    assert_eq!(clone.try_get().unwrap(), 5);
+    assert!(errors
+        .text()
+        .ends_with("Must be at least 3.\nMust be at least 3.\n"));
+}
+
+#[test]
+fn test_default_hint() {
+    let plain = Theme::plain();
+    assert_eq!(
+        plain.paint_prompt("Port: ", Some("[8080]"), false),
+        "Port [8080]: "
+    );
+    assert_eq!(
+        plain.paint_prompt("Port:\n", Some("[8080]"), false),
+        "Port [8080]:\n"
+    );
+    assert_eq!(
+        plain.paint_prompt("Port", Some("[8080]"), false),
+        "Port [8080] "
+    );
+    assert_eq!(plain.paint_prompt("", Some("[8080]"), false), "");
+    assert_eq!(
+        Theme::colourful().paint_prompt("Port: ", Some("[8080]"), false),
+        "? Port [8080]: "
+    );
+}
+
+#[test]
+fn test_initial_text() {
+    let prompts = Output::default();
+    let port = |text: &str| {
+        input::<u16>()
+            .msg("Port: ")
+            .initial_text(8080)
+            .reading_from(reader(text))
+            .prompting_on(prompts.writer())
+    };
+    assert_eq!(port("\n").try_get().unwrap(), 8080);
+    assert_eq!(prompts.text(), "Port [8080]: ");
+    assert_eq!(port("443\n").try_get().unwrap(), 443);
+    assert_eq!(port("\n").default(80).try_get().unwrap(), 8080);
+    assert_eq!(port("").default(80).try_get().unwrap(), 8080);
+
+    let errors = Output::default();
+    let rejected = port("\n").min(9000).errors_on(errors.writer());
+    assert_eq!(
+        rejected.try_get().unwrap_err().kind(),
+        io::ErrorKind::InvalidData
+    );
+    assert!(errors.text().contains("1 | 8080"));
+}
+
+#[test]
+fn test_show_default() {
+    let prompts = Output::default();
+    let port = |msg: &str| {
+        input::<u16>()
+            .msg(msg)
+            .reading_from(reader("\n"))
+            .prompting_on(prompts.writer())
+            .default(8080)
+    };
+    let shown = |builder: InputBuilderOnce<u16>| {
+        prompts.0.borrow_mut().clear();
+        assert_eq!(builder.try_get().unwrap(), 8080);
+        prompts.text()
+    };
+    assert_eq!(shown(port("Port: ")), "Port: ");
+    assert_eq!(shown(port("Port: ").show_default()), "Port [8080]: ");
+    assert_eq!(
+        shown(port("Port: ").default_format(|x| format!("(default {})", x))),
+        "Port (default 8080): "
+    );
+    assert_eq!(
+        shown(port("Port: ").show_default().hide_default()),
+        "Port: "
+    );
+    assert_eq!(shown(port("").show_default()), "");
+
+    // Types without `Display` can still have a default value.
+    #[derive(Debug, PartialEq)]
+    struct Level(u8);
+    impl FromStr for Level {
+        type Err = ();
+        fn from_str(s: &str) -> Result<Self, ()> {
+            s.parse().map(Level).map_err(|_| ())
+        }
+    }
+    let level = input::<Level>()
+        .reading_from(reader("\n"))
+        .default(Level(3));
+    assert_eq!(level.try_get().unwrap(), Level(3));
+}
+
+#[test]
+fn test_lazy_default() {
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    let mut default = DefaultValue::With(Rc::new(move || {
+        counter.set(counter.get() + 1);
+        8080
+    }));
+    assert_eq!(calls.get(), 0);
+    assert_eq!(*default.cached(), 8080);
+    assert_eq!(*default.cached(), 8080);
+    assert_eq!(default.value(), 8080);
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn test_default_from_env() {
+    english();
+    let port = input::<u16>().min(1024);
+    assert_eq!(env_default(&port, "PORT", None).unwrap(), None);
+    assert_eq!(env_default(&port, "PORT", Some("".into())).unwrap(), None);
+    assert_eq!(
+        env_default(&port, "PORT", Some("8080".into())).unwrap(),
+        Some(8080)
+    );
+    let err = env_default(&port, "PORT", Some("99999".into())).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("environment variable PORT is not valid: "));
+    let err = env_default(&port, "PORT", Some("80".into())).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "environment variable PORT is not valid: Must be at least 1024."
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStringExt;
+        let value = std::ffi::OsString::from_vec(b"80\xff".to_vec());
+        let err = env_default(&port, "PORT", Some(value)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "environment variable PORT is not valid unicode"
+        );
+    }
+}
+
+#[test]
+fn test_list() {
+    english();
+    let list = input_list::<u32>().min(2).err("Not a number.");
+    assert_eq!(list.parse(" 2, 3 ,4 ", 1).unwrap(), vec![2, 3, 4]);
+    assert_eq!(list.parse("", 1).unwrap(), Vec::<u32>::new());
+    assert_eq!(
+        list.parse("2, 3, x", 1).unwrap_err().msg,
+        "Item 3 (`x`): Not a number."
+    );
+    assert_eq!(
+        input_list::<u32>().parse("2, 3, x", 1).unwrap_err().msg,
+        "Item 3 (`x`) is not a valid u32."
+    );
+    assert_eq!(
+        input_list::<u32>().min(2).parse("2, 1", 1).unwrap_err().msg,
+        "Item 2 (`1`): Must be at least 2."
+    );
+
+    let words = input_list::<String>()
+        .delimiter(Delimiter::Whitespace)
+        .min_items(2)
+        .max_items(3)
+        .unique();
+    assert_eq!(words.parse("a  b", 1).unwrap(), vec!["a", "b"]);
+    assert_eq!(
+        words.parse("a", 1).unwrap_err().msg,
+        "Must have at least 2 items."
+    );
+    assert_eq!(
+        words.parse("a b c d", 1).unwrap_err().msg,
+        "Must have at most 3 items."
+    );
+    assert_eq!(
+        words.parse("a b a", 1).unwrap_err().msg,
+        "Item 3 (`a`) is given more than once."
+    );
+
+    let colours = input_list::<String>()
+        .delimiter(':')
+        .select(["red".to_string(), "green".to_string()]);
+    let rejection = colours.parse("red:gren", 1).unwrap_err();
+    assert_eq!(rejection.suggestion, Some("red:green".to_string()));
+}
+
+#[test]
+fn test_collect() {
+    let path = env::temp_dir().join(format!("read_input_collect_{}", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let prompts = Output::default();
+    let numbers = input::<u32>()
+        .msg("Number {n}: ")
+        .reading_from(reader("1\n2\nundo\n3\ndone\n4\n"))
+        .prompting_on(prompts.writer())
+        .theme(Theme::plain().echo(true).success_prefix("> "))
+        .history(History::at(&path))
+        .collect_until("done")
+        .undo("undo")
+        .get();
+    assert_eq!(numbers, vec![1, 3]);
+    assert_eq!(
+        prompts.text(),
+        "Number 1: > 1\nNumber 2: > 2\nNumber 3: Number 2: > 3\nNumber 3: "
+    );
+    assert_eq!(History::at(&path).entries(), vec!["1", "2", "3"]);
+    fs::remove_file(&path).unwrap();
+
+    let quiet = || input::<u32>().prompting_on(Output::default().writer());
+    let lines = quiet().reading_from(reader("5\n6\n\n7\n"));
+    assert_eq!(lines.collect_until_empty().get(), vec![5, 6]);
+    let ended = quiet().reading_from(reader("5\n6"));
+    assert_eq!(ended.collect_until("done").get(), vec![5, 6]);
+    let limited = quiet().reading_from(reader("1\n2\n3\n"));
+    assert_eq!(limited.collect_until_empty().max_items(2).get(), vec![1, 2]);
+    let counted = quiet().reading_from(reader("1\n2\n3\n"));
+    assert_eq!(counted.collect_n(2).get(), vec![1, 2]);
+    let short = quiet().reading_from(reader("1\n"));
+    let err = short.collect_n(2).try_get().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn test_tuple() {
+    english();
+    let point = input_tuple::<(i32, f64)>();
+    assert_eq!(point.parse(" 3  4.5 ", 1).unwrap(), (3, 4.5));
+    assert_eq!(
+        point.parse("3 x", 1).unwrap_err().msg,
+        "The second value is not a valid f64."
+    );
+    assert_eq!(
+        point.parse("3", 1).unwrap_err().msg,
+        "Expected 2 values but got 1."
+    );
+    assert_eq!(
+        input_tuple::<(String, std::net::IpAddr)>()
+            .parse("a b", 1)
+            .unwrap_err()
+            .msg,
+        "The second value is not a valid IpAddr."
+    );
+
+    let range = input_tuple::<(u8, u8, u8)>()
+        .separator(Delimiter::Comma)
+        .add_err_test(|&(a, b, c)| a < b && b < c, "{input} is not increasing.");
+    assert_eq!(range.parse("1, 2,3", 1).unwrap(), (1, 2, 3));
+    assert_eq!(
+        range.parse("1, 3, 2", 1).unwrap_err().msg,
+        "1, 3, 2 is not increasing."
+    );
+    assert_eq!(
+        range
+            .clone()
+            .err("Try again.")
+            .parse("1, x, 2", 1)
+            .unwrap_err()
+            .msg,
+        "Try again."
+    );
+    assert_eq!(
+        range.clone().clear_tests().parse("3, 2, 1", 1).unwrap(),
+        (3, 2, 1)
+    );
+
+    let prompts = Output::default();
+    let sized = |text: &str| {
+        input_tuple::<(u32, u32)>()
+            .msg("Size: ")
+            .toggle_msg_repeat()
+            .initial_text("640 480")
+            .reading_from(reader(text))
+            .prompting_on(prompts.writer())
+            .batch(false)
+    };
+    assert_eq!(sized("\n").get(), (640, 480));
+    assert_eq!(sized("1\n2 3\n").get(), (2, 3));
+    assert_eq!(prompts.text().matches("Size").count(), 3);
+}
+
+#[test]
+fn test_pattern() {
+    english();
+    let person = input_tuple::<(String, u8)>().pattern("{} is {} years old");
+    assert_eq!(
+        person.parse("Ada is 36 years old", 1).unwrap(),
+        ("Ada".to_string(), 36)
+    );
+    assert_eq!(
+        person.parse("Ada is 36", 1).unwrap_err().msg,
+        "Must look like `{} is {} years old`."
+    );
+    assert_eq!(
+        person.parse("Ada is old years old", 1).unwrap_err().msg,
+        "The second value is not a valid u8."
+    );
+
+    let point = input_tuple::<(i32, i32)>().pattern("({}, {})");
+    assert_eq!(point.parse("(1, -2)", 1).unwrap(), (1, -2));
+    let (size,) = input_tuple::<(u32,)>()
+        .pattern("{}px")
+        .parse("12px", 1)
+        .unwrap();
+    assert_eq!(size, 12);
+
+    let (w, h): (u32, u32) = crate::scan!(
+        "{}x{}",
+        reading_from = reader("4x3\n"),
+        prompting_on = Output::default().writer(),
+    );
+    assert_eq!((w, h), (4, 3));
+}
+
+#[test]
+fn test_input_macro() {
+    // Only built, since it reads stdin.
+    let _empty = || -> u8 { crate::input!() };
+    let quiet = || Output::default().writer();
+    let name: String = crate::input!(reading_from = reader("Ann\n"), prompting_on = quiet());
+    assert_eq!(name, "Ann");
+    let name: String = crate::input!(
+        "Name: ",
+        reading_from = reader("\n"),
+        prompting_on = quiet(),
+        initial_text = "Bo",
+    );
+    assert_eq!(name, "Bo");
+    let age = crate::input!(
+        "Age: ",
+        u8,
+        0..=130,
+        reading_from = reader("200\n\n"),
+        prompting_on = quiet(),
+        errors_on = quiet(),
+        batch = false,
+        default = 18,
+    );
+    assert_eq!(age, 18);
+    let year: i32 = crate::input!(
+        _,
+        min = 1900,
+        reading_from = reader("1999\n"),
+        prompting_on = quiet()
+    );
+    assert_eq!(year, 1999);
+    let colour = crate::input!(
+        String,
+        ["red".to_string(), "blue".to_string()],
+        reading_from = reader("blue\n"),
+        prompting_on = quiet(),
+    );
+    assert_eq!(colour, "blue");
+}
+
+#[test]
+fn test_scanner() {
+    let mut scanner = Scanner::new("3\n 1 2\n\n  -4 x\n".as_bytes());
+    let count: usize = scanner.token().unwrap();
+    assert_eq!(scanner.tokens::<i32>(count).unwrap(), vec![1, 2, -4]);
+    assert!(!scanner.is_done().unwrap());
+
+    let err = scanner.token::<u8>().unwrap_err();
+    assert_eq!((err.line(), err.column()), (4, 6));
+    assert_eq!(err.to_string(), "line 4, column 6: `x` is not a valid u8");
+    assert!(scanner.is_done().unwrap());
+    let err = scanner.token::<u8>().unwrap_err();
+    assert!(matches!(err.kind(), ScanErrorKind::UnexpectedEof));
+    assert_eq!(err.line(), 5);
+}
+
+#[test]
+fn test_iter() {
+    english();
+    let data = "5\n2\nx\n7\r\n";
+    let builder = input::<u32>().min(3);
+    let values: Vec<_> = builder.iter_from(data.as_bytes()).collect();
+    assert_eq!(values.len(), 4);
+    assert_eq!(values[0].as_ref().unwrap(), &5);
+    assert_eq!(values[3].as_ref().unwrap(), &7);
+    match &values[1] {
+        Err(InputError::Invalid { line, input, msg }) => {
+            assert_eq!((*line, input.as_str()), (2, "2"));
+            assert_eq!(msg, "Must be at least 3.");
+        }
+        _ => panic!("line 2 should be invalid"),
+    }
+
+    let skipped = builder
+        .iter_from(data.as_bytes())
+        .on_invalid(OnInvalid::Skip);
+    assert_eq!(skipped.map(Result::unwrap).collect::<Vec<_>>(), vec![5, 7]);
+    let stopped = builder
+        .iter_from(data.as_bytes())
+        .on_invalid(OnInvalid::Stop);
+    assert_eq!(stopped.count(), 2);
+
+    let data: &[u8] = b"5\n\xff4\n7\n";
+    let values: Vec<_> = builder.iter_from(data).collect();
+    assert_eq!(values.len(), 3);
+    match &values[1] {
+        Err(InputError::Invalid { line, input, msg }) => {
+            assert_eq!((*line, input.as_str()), (2, "\u{fffd}4"));
+            assert_eq!(msg, "Input is not valid UTF-8.");
+        }
+        _ => panic!("line 2 should be invalid"),
+    }
+    assert_eq!(values[2].as_ref().unwrap(), &7);
+    let skipped = builder.iter_from(data).on_invalid(OnInvalid::Skip);
+    assert_eq!(skipped.map(Result::unwrap).collect::<Vec<_>>(), vec![5, 7]);
+    let lossy = input::<String>().lossy_utf8();
+    let lines: Vec<_> = lossy.iter_from(data).map(Result::unwrap).collect();
+    assert_eq!(lines, vec!["5", "\u{fffd}4", "7"]);
+    let lines: Vec<_> = input_os_string()
+        .iter_from(data)
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(lines[1].len(), 2);
+}
+
+#[test]
+fn test_batch_report() {
+    let error = InputError::Invalid {
+        line: 12,
+        input: "17".to_string(),
+        msg: "Must be at least 18.".to_string(),
+    };
+    assert_eq!(
+        error.report(),
+        "error: Must be at least 18.\n  --> line 12\n   |\n12 | 17\n   |"
+    );
+}
+
+#[test]
+fn test_batch_mode() {
+    english();
+    let errors = Output::default();
+    let age = input::<u32>()
+        .min(18)
+        .reading_from(reader("20\n17\n"))
+        .prompting_on(Output::default().writer())
+        .errors_on(errors.writer());
+    assert_eq!(age.try_get().unwrap(), 20);
+
+    let err = age.try_get().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    let error = err.get_ref().and_then(|e| e.downcast_ref::<InputError>());
+    match error {
+        Some(InputError::Invalid { line, input, msg }) => {
+            assert_eq!((*line, input.as_str()), (2, "17"));
+            assert_eq!(msg, "Must be at least 18.");
+        }
+        _ => panic!("expected an invalid line, got {:?}", error),
+    }
+    assert_eq!(
+        errors.text(),
+        "error: Must be at least 18.\n --> line 2\n  |\n2 | 17\n  |\n"
+    );
+    let err = age.try_get().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+    let with_default = input().reading_from(reader("")).default(7u32);
+    assert_eq!(with_default.try_get().unwrap(), 7);
+
+    let errors = Output::default();
+    let retry = input::<u32>()
+        .reading_from(reader("x\n5\n"))
+        .batch(false)
+        .err("Not a number.")
+        .prompting_on(Output::default().writer())
+        .errors_on(errors.writer());
+    assert_eq!(retry.try_get().unwrap(), 5);
+    assert_eq!(errors.text(), "Not a number.\n");
+
+    let errors = Output::default();
+    let name = input::<String>()
+        .reading_from(reader("x\n5\n"))
+        .prompting_on(Output::default().writer())
+        .errors_on(errors.writer());
+    let only_x = name.clone().add_err_test(|x| x == "x", "Only x.");
+    assert_eq!(name.try_get().unwrap(), "x");
+    assert!(only_x.try_get().is_err());
+    assert!(errors.text().contains(" --> line 2\n"));
+}
+
+// Runs in a child process given `x` and `5` on stdin by `test_stdin_line_numbers`.
+fn answer_two_questions() {
+    english();
+    let quiet = || Output::default().writer();
+    let name: String = input().prompting_on(quiet()).get();
+    assert_eq!(name, "x");
+    let err = input::<u8>().min(10).prompting_on(quiet()).try_get();
+    assert_eq!(err.unwrap_err().kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_stdin_line_numbers() {
+    if env::var_os("READ_INPUT_TWO_QUESTIONS").is_some() {
+        return answer_two_questions();
+    }
+    let mut child = std::process::Command::new(env::current_exe().unwrap())
+        .args(["tests::test_stdin_line_numbers", "--exact", "--nocapture"])
+        .env("READ_INPUT_TWO_QUESTIONS", "1")
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"x\n5\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+    let errors = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success(), "{}", errors);
+    assert!(errors.contains(" --> line 2\n"), "{}", errors);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_raw_bytes() {
+    use std::os::unix::ffi::OsStrExt;
+    let bytes = b"caf\xe9.txt\n";
+    let lossy = String::from_utf8_lossy(bytes);
+    let path = parse_line(&lossy, Some(bytes), &input_path(), 1).unwrap();
+    assert_eq!(path.as_os_str().as_bytes(), b"caf\xe9.txt");
+
+    let rejected = input_path().add_err_test(|path| path.extension().is_none(), "No extensions.");
+    assert_eq!(
+        parse_line(&lossy, Some(bytes), &rejected, 1)
+            .unwrap_err()
+            .msg,
+        "No extensions."
+    );
+}
+
+#[test]
+fn test_path_constraints() {
+    english();
+    let path = |builder: InputBuilder<PathBuf>, input: &str| {
+        parse_with_builder(builder, input.to_string())
+    };
+    assert!(path(input().must_exist().is_file(), "Cargo.toml").is_ok());
+    assert_eq!(
+        path(input().must_exist(), "missing.toml"),
+        Err("Must be an existing path.".to_string())
+    );
+    assert_eq!(
+        path(input().must_not_exist(), "src"),
+        Err("Must not exist yet.".to_string())
+    );
+    assert_eq!(
+        path(input().is_file(), "src"),
+        Err("Must be a file.".to_string())
+    );
+    assert!(path(input().is_dir().readable(), "src").is_ok());
+
+    let dir = env::temp_dir().join(format!("read_input-paths-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("notes.txt");
+    fs::write(&file, "notes").unwrap();
+    let dir_text = dir.to_str().unwrap();
+    let file_text = file.to_str().unwrap();
+    assert!(path(input().writable(), dir_text).is_ok());
+    assert!(path(input().readable().writable(), file_text).is_ok());
+    assert_eq!(fs::read_to_string(&file).unwrap(), "notes");
+    let missing = dir.join("missing.txt");
+    assert!(path(input().writable(), missing.to_str().unwrap()).is_ok());
+    let nested = dir.join("missing").join("notes.txt");
+    assert_eq!(
+        path(input().writable(), nested.to_str().unwrap()),
+        Err("Must be writable.".to_string())
+    );
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(path(input().extension(["json", ".TOML"]), "Cargo.toml").is_ok());
+    assert_eq!(
+        path(input().extension(["json", "yaml"]), "Cargo.toml"),
+        Err("Must end in one of: .json, .yaml.".to_string())
+    );
+    assert_eq!(
+        path(input().is_dir_err("{input} is not a folder."), "Cargo.toml"),
+        Err("Cargo.toml is not a folder.".to_string())
+    );
+
+    let var = |name: &str| match name {
+        "DIR" => Some("src".into()),
+        "HOME" | "USERPROFILE" => Some("/home/me".into()),
+        _ => None,
+    };
+    let expand = |text: &str| expand_with(PathBuf::from(text), &var);
+    assert_eq!(expand("$DIR/../${DIR}"), PathBuf::from("src/../src"));
+    assert_eq!(expand("$UNSET/a$"), PathBuf::from("$UNSET/a$"));
+    assert_eq!(expand("~/a"), PathBuf::from("/home/me").join("a"));
+    assert_eq!(expand("~a"), PathBuf::from("~a"));
+    let absolute = path(input().absolute().must_exist(), "Cargo.toml").unwrap();
+    assert_eq!(absolute, env::current_dir().unwrap().join("Cargo.toml"));
+}
+
+#[test]
+fn test_string_constraints() {
+    english();
+    let text =
+        |builder: InputBuilder<String>, input: &str| parse_with_builder(builder, input.to_string());
+    assert!(text(input().min_len(3).max_len(4), "äöü").is_ok());
+    assert_eq!(
+        text(input().min_len(3), "ab"),
+        Err("Must be at least 3 characters long.".to_string())
+    );
+    assert_eq!(
+        text(input().max_len(1), "ab"),
+        Err("Must be at most 1 character long.".to_string())
+    );
+    assert_eq!(
+        text(input().len_range(2..5), "abcde"),
+        Err("Must be between 2 and 4 characters long.".to_string())
+    );
+    assert_eq!(
+        text(input().non_empty(), ""),
+        Err("Must not be empty.".to_string())
+    );
+    assert_eq!(
+        text(input().ascii_only(), "café"),
+        Err("Must only contain ASCII characters.".to_string())
+    );
+    assert!(text(input().alphanumeric(), "café42").is_ok());
+    assert_eq!(
+        text(input().alphanumeric_err("'{input}' has symbols."), "a-b"),
+        Err("'a-b' has symbols.".to_string())
+    );
+    let colour = input().one_of_ignore_case(["Red", "Green"]);
+    assert_eq!(text(colour.clone(), "gREEN"), Ok("gREEN".to_string()));
+    assert_eq!(
+        parse_input("Gren", &colour, 1).unwrap_err().suggestion,
+        Some("Green".to_string())
+    );
+    assert_eq!(
+        bundled("de").unwrap().translate(&Message::MinLength(10)),
+        Some("Muss mindestens 10 Zeichen lang sein.".to_string())
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_matches() {
+    let code = input::<String>().matches(regex::Regex::new("^[A-Z]{2}[0-9]$").unwrap());
+    assert!(parse_with_builder(code.clone(), "AB1".to_string()).is_ok());
+    assert_eq!(
+        parse_with_builder(code, "ab1".to_string()),
+        Err("Must match `^[A-Z]{2}[0-9]$`.".to_string())
+    );
+}
+
+#[test]
+fn test_float_constraints() {
+    english();
+    let number =
+        |builder: InputBuilder<f64>, input: &str| parse_with_builder(builder, input.to_string());
+    assert!(number(input().min(0.0), "inf").is_ok());
+    assert_eq!(
+        number(input().min(0.0).finite(), "inf"),
+        Err("Must be a finite number.".to_string())
+    );
+    assert!(number(input().not_nan(), "-inf").is_ok());
+    assert_eq!(
+        number(input().not_nan(), "nan"),
+        Err("Must be a number, not NaN.".to_string())
+    );
+    assert!(number(input().max_decimal_places(2), "19.90").is_ok());
+    assert_eq!(
+        number(input().max_decimal_places(2), "19.999"),
+        Err("Must have at most 2 decimal places.".to_string())
+    );
+    assert_eq!(
+        number(input().max_decimal_places(0), "1.5"),
+        Err("Must be a whole number.".to_string())
+    );
+    assert!(number(input().step(0.25), "-1.75").is_ok());
+    assert!(number(input().step(0.1), "0.3").is_ok());
+    assert!(number(input().step(0.5), "1e300").is_ok());
+    assert_eq!(
+        number(input().step(0.25), "1.3"),
+        Err("Must be a multiple of 0.25.".to_string())
+    );
+    assert_eq!(
+        number(input().approx_not(1.0, 0.01), "1.005"),
+        Err("Must not be within 0.01 of 1.".to_string())
+    );
+    assert!(number(input().approx_not(1.0, 0.01), "1.02").is_ok());
+
+    let hours = input::<f32>().step(0.1).finite().default(0.0);
+    assert_eq!(parse_input("0.3", &hours.builder, 1).ok(), Some(0.3));
+}
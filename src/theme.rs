@@ -0,0 +1,269 @@
+//! Styling of prompts, hints, errors and accepted values.
+//!
+//! A [`Theme`] is set for a single builder with [`InputBuild::theme`](crate::InputBuild::theme)
+//! or for every builder with [`set_default`]. The default theme writes plain text like earlier
+//! versions of this crate.
+//!
+//! Colour is only used when the prompt is written to a terminal and the `NO_COLOR` environment
+//! variable is not set.
+//!
+//! ```no_run
+//! # use read_input::prelude::*;
+//! use read_input::theme::{self, Theme};
+//! theme::set_default(Theme::colourful());
+//! let name: String = input().msg("Name: ").get();
+//! ```
+
+use std::{env, sync::RwLock};
+
+/// The eight standard terminal colours.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+/// Colour and weight of a piece of text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Style {
+    fg: Option<Colour>,
+    bold: bool,
+}
+
+impl Style {
+    /// Creates a style that leaves text unchanged.
+    pub const fn new() -> Self {
+        Self {
+            fg: None,
+            bold: false,
+        }
+    }
+    /// Sets the text colour.
+    pub const fn fg(mut self, colour: Colour) -> Self {
+        self.fg = Some(colour);
+        self
+    }
+    /// Makes text bold.
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+    // Wraps text in ANSI escape codes.
+    fn paint(self, text: &str, colour: bool) -> String {
+        if !colour || self == Self::new() || text.is_empty() {
+            return text.to_string();
+        }
+        let mut codes = Vec::new();
+        if self.bold {
+            codes.push("1".to_string());
+        }
+        if let Some(fg) = self.fg {
+            codes.push((30 + fg as u8).to_string());
+        }
+        format!("\u{1b}[{}m{}\u{1b}[0m", codes.join(";"), text)
+    }
+}
+
+/// When to use colour.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum When {
+    /// Use colour when writing to a terminal and `NO_COLOR` is not set.
+    Auto,
+    /// Always use colour.
+    Always,
+    /// Never use colour.
+    Never,
+}
+
+/// Styles and symbols used when writing prompts, hints, errors and accepted values.
+///
+/// Hints are default values, lists of recent values and lists of completions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Theme {
+    prompt: Style,
+    hint: Style,
+    error: Style,
+    success: Style,
+    prompt_prefix: String,
+    error_prefix: String,
+    success_prefix: String,
+    echo: bool,
+    colour: When,
+}
+
+impl Theme {
+    /// Plain text without symbols. This is the default theme.
+    pub fn plain() -> Self {
+        Self {
+            prompt: Style::new(),
+            hint: Style::new(),
+            error: Style::new(),
+            success: Style::new(),
+            prompt_prefix: String::new(),
+            error_prefix: String::new(),
+            success_prefix: String::new(),
+            echo: false,
+            colour: When::Auto,
+        }
+    }
+    /// Bold prompts marked with `?`, red errors marked with `✖` and accepted values echoed in
+    /// green after `✔`.
+    pub fn colourful() -> Self {
+        Self {
+            prompt: Style::new().bold(),
+            hint: Style::new().fg(Colour::Cyan),
+            error: Style::new().fg(Colour::Red),
+            success: Style::new().fg(Colour::Green),
+            prompt_prefix: "? ".to_string(),
+            error_prefix: "✖ ".to_string(),
+            success_prefix: "✔ ".to_string(),
+            echo: true,
+            colour: When::Auto,
+        }
+    }
+    /// Replaces symbols that are not ASCII with ASCII ones, for terminals that can not show them.
+    pub fn ascii(mut self) -> Self {
+        for (prefix, ascii) in [
+            (&mut self.prompt_prefix, "? "),
+            (&mut self.error_prefix, "x "),
+            (&mut self.success_prefix, "> "),
+        ] {
+            if !prefix.is_ascii() {
+                *prefix = ascii.to_string();
+            }
+        }
+        self
+    }
+    /// Sets the style of prompt messages.
+    pub fn prompt(mut self, style: Style) -> Self {
+        self.prompt = style;
+        self
+    }
+    /// Sets the style of hints.
+    pub fn hint(mut self, style: Style) -> Self {
+        self.hint = style;
+        self
+    }
+    /// Sets the style of error messages.
+    pub fn error(mut self, style: Style) -> Self {
+        self.error = style;
+        self
+    }
+    /// Sets the style of accepted values.
+    pub fn success(mut self, style: Style) -> Self {
+        self.success = style;
+        self
+    }
+    /// Sets the symbol written before prompt messages.
+    pub fn prompt_prefix(mut self, prefix: impl ToString) -> Self {
+        self.prompt_prefix = prefix.to_string();
+        self
+    }
+    /// Sets the symbol written before error messages.
+    pub fn error_prefix(mut self, prefix: impl ToString) -> Self {
+        self.error_prefix = prefix.to_string();
+        self
+    }
+    /// Sets the symbol written before accepted values.
+    pub fn success_prefix(mut self, prefix: impl ToString) -> Self {
+        self.success_prefix = prefix.to_string();
+        self
+    }
+    /// Sets whether accepted values are written back once input is valid.
+    pub fn echo(mut self, echo: bool) -> Self {
+        self.echo = echo;
+        self
+    }
+    /// Sets when colour is used.
+    pub fn colour(mut self, when: When) -> Self {
+        self.colour = when;
+        self
+    }
+    // Whether to write escape codes to an output that may be a terminal.
+    pub(crate) fn use_colour(&self, terminal: bool) -> bool {
+        match self.colour {
+            When::Always => true,
+            When::Never => false,
+            When::Auto => terminal && env::var_os("NO_COLOR").filter(|x| !x.is_empty()).is_none(),
+        }
+    }
+    // Prompt message with a hint, such as the default value, before its final colon.
+    pub(crate) fn paint_prompt(&self, msg: &str, hint: Option<&str>, colour: bool) -> String {
+        // Without a message there is no prompt to put a hint in.
+        if msg.is_empty() {
+            return String::new();
+        }
+        let hint = match hint {
+            Some(hint) => hint,
+            None => {
+                return format!(
+                    "{}{}",
+                    self.prompt.paint(&self.prompt_prefix, colour),
+                    self.prompt.paint(msg, colour)
+                )
+            }
+        };
+        let body = msg.trim_end();
+        let (body, end) = match body.strip_suffix(':') {
+            Some(body) => (body.trim_end(), &msg[body.len()..]),
+            None if body.is_empty() => ("", " "),
+            None => (body, &msg[body.len()..]),
+        };
+        let space = if body.is_empty() { "" } else { " " };
+        let end = if end.is_empty() { " " } else { end };
+        format!(
+            "{}{}{}{}{}",
+            self.prompt.paint(&self.prompt_prefix, colour),
+            self.prompt.paint(body, colour),
+            space,
+            self.hint.paint(hint, colour),
+            self.prompt.paint(end, colour)
+        )
+    }
+    pub(crate) fn paint_hint(&self, hint: &str, colour: bool) -> String {
+        self.hint.paint(hint, colour)
+    }
+    pub(crate) fn paint_error(&self, err: &str, colour: bool) -> String {
+        self.error
+            .paint(&format!("{}{}", self.error_prefix, err), colour)
+    }
+    pub(crate) fn paint_success(&self, value: &str, colour: bool) -> Option<String> {
+        if self.echo {
+            Some(
+                self.success
+                    .paint(&format!("{}{}", self.success_prefix, value), colour),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::plain()
+    }
+}
+
+static DEFAULT: RwLock<Option<Theme>> = RwLock::new(None);
+
+/// Sets the theme used by builders that have no theme of their own.
+pub fn set_default(theme: Theme) {
+    *DEFAULT.write().unwrap_or_else(|e| e.into_inner()) = Some(theme);
+}
+
+/// Returns the theme used by builders that have no theme of their own.
+pub fn default() -> Theme {
+    DEFAULT
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_default()
+}
@@ -0,0 +1,369 @@
+//! Reading several values of different types from a single line.
+//!
+//! [`input_tuple`](crate::shortcut::input_tuple) creates a [`TupleBuilder`] for tuples of one
+//! to eight values. Each value can have its own type.
+//!
+//! ```no_run
+//! # use read_input::prelude::*;
+//! use read_input::list::Delimiter;
+//! let (x, y): (f64, f64) = input_tuple().msg("Point: ").separator(Delimiter::Comma).get();
+//! ```
+//!
+//! The [`scan!`](crate::scan) macro reads values that are surrounded by other text.
+
+use crate::{
+    completion::Completer,
+    core::{placeholder, read_with, translated, Rejection},
+    history::History,
+    list::Delimiter,
+    messages::{Message, Translate},
+    template::render,
+    test_generators::InsideFunc,
+    theme::Theme,
+    InputBuild, InputBuilder, Test,
+};
+use std::{
+    any::type_name,
+    cell::RefCell,
+    io::{self, BufRead, Write},
+    rc::Rc,
+    str::FromStr,
+};
+
+/// Tuples that can be read by a [`TupleBuilder`].
+///
+/// It is implemented for tuples of one to eight values whose types implement [`FromStr`].
+pub trait Tuple: Sized {
+    /// Number of values in the tuple.
+    const ARITY: usize;
+    /// Parses one item per value. On failure returns the position of the value that could not
+    /// be parsed, counting from 0, and the name of its type.
+    fn parse_items(items: &[&str]) -> Result<Self, (usize, String)>;
+}
+
+// Name of a type without module paths, such as `Vec<String>`.
+pub(crate) fn short_type_name<T>() -> String {
+    let mut name = String::new();
+    let mut segment = 0;
+    let mut chars = type_name::<T>().chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.next_if_eq(&':').is_some() {
+            name.truncate(segment);
+        } else {
+            name.push(c);
+            if !(c.is_alphanumeric() || c == '_') {
+                segment = name.len();
+            }
+        }
+    }
+    name
+}
+
+macro_rules! impl_tuple {
+    ($($arity:literal => ($($index:tt $name:ident),+);)+) => {
+        $(
+            impl<$($name: FromStr),+> Tuple for ($($name,)+) {
+                const ARITY: usize = $arity;
+                fn parse_items(items: &[&str]) -> Result<Self, (usize, String)> {
+                    Ok(($(
+                        $name::from_str(items[$index])
+                            .map_err(|_| ($index, short_type_name::<$name>()))?,
+                    )+))
+                }
+            }
+        )+
+    };
+}
+
+impl_tuple! {
+    1 => (0 A);
+    2 => (0 A, 1 B);
+    3 => (0 A, 1 B, 2 C);
+    4 => (0 A, 1 B, 2 C, 3 D);
+    5 => (0 A, 1 B, 2 C, 3 D, 4 E);
+    6 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+    7 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+    8 => (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+}
+
+// Splits a line into the parts matched by `{}` in a pattern such as `{} is {} years old`.
+// `pattern` holds the text around the placeholders.
+fn match_pattern<'a>(pattern: &[String], line: &'a str) -> Option<Vec<&'a str>> {
+    let (first, rest) = pattern.split_first()?;
+    let (last, middle) = rest.split_last()?;
+    let mut line = line.strip_prefix(first.as_str())?;
+    line = line.strip_suffix(last.as_str())?;
+    let mut fields = Vec::with_capacity(rest.len());
+    for text in middle {
+        let end = line.find(text.as_str())?;
+        fields.push(line[..end].trim());
+        line = &line[end + text.len()..];
+    }
+    fields.push(line.trim());
+    Some(fields)
+}
+
+/// Reads one line that matches a pattern and returns the values in place of each `{}`.
+///
+/// The type of each value is inferred and reading is retried until the line matches and every
+/// value can be parsed. Settings of [`TupleBuilder`] that take one argument can follow the
+/// pattern.
+///
+/// ```no_run
+/// # use read_input::prelude::*;
+/// let (name, age): (String, u8) = scan!("{} is {} years old");
+/// let (x, y): (i32, i32) = scan!("({}, {})", msg = "Point: ", err = "Type a point like (1, 2).");
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($pattern:expr $(, $setting:ident = $value:expr)* $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::InputBuild as _;
+        $crate::tuple::TupleBuilder::new()
+            .pattern($pattern)
+            $(.$setting($value))*
+            .get()
+    }};
+}
+
+/// 'builder' used to store the settings that are used to fetch a tuple from one line.
+///
+/// Values are separated by whitespace unless [`TupleBuilder::separator`] is used.
+///
+/// `.get()` method only takes these settings by reference so can be called multiple times.
+pub struct TupleBuilder<T: Tuple> {
+    builder: InputBuilder<String>,
+    separator: Delimiter,
+    pattern: Option<Vec<String>>,
+    tests: Vec<Test<T>>,
+}
+
+impl<T: Tuple> TupleBuilder<T> {
+    /// Creates a new instance of `TupleBuilder` with default settings.
+    pub fn new() -> Self {
+        Self {
+            builder: InputBuilder::new(),
+            separator: Delimiter::Whitespace,
+            pattern: None,
+            tests: Vec::new(),
+        }
+    }
+    /// 'gets' the input form the user.
+    ///
+    /// Panics if unable to read input line.
+    pub fn get(&self) -> T {
+        self.try_get().expect("Failed to read line")
+    }
+    /// 'gets' the input form the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if unable to read input line.
+    pub fn try_get(&self) -> io::Result<T> {
+        read_with(&self.builder, None, None, |input, _, attempt| {
+            self.parse(input, attempt)
+        })
+    }
+    /// Sets where the line is split into values.
+    pub fn separator(mut self, separator: impl Into<Delimiter>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+    /// Reads values from lines that look like `pattern`, with `{}` in place of each value.
+    ///
+    /// This replaces the separator. Lines that do not match the text around the placeholders
+    /// are rejected.
+    ///
+    /// ```no_run
+    /// # use read_input::prelude::*;
+    /// let (w, h): (u32, u32) = input_tuple().pattern("{}x{}").msg("Size: ").get();
+    /// ```
+    pub fn pattern(mut self, pattern: impl ToString) -> Self {
+        let pattern = pattern.to_string();
+        self.pattern = Some(pattern.split("{}").map(str::to_string).collect());
+        self
+    }
+    // Internal function for adding tests.
+    fn test_err_opt(mut self, func: Rc<dyn Fn(&T) -> bool>, err: Option<String>) -> Self {
+        self.tests.push(Test {
+            func,
+            err,
+            candidates: None,
+            message: None,
+        });
+        self
+    }
+    // Internal function for adding `inside` constraints on the whole tuple.
+    fn inside_err_opt<U: InsideFunc<T>>(mut self, constraint: U, err: Option<String>) -> Self {
+        self.tests.push(Test {
+            candidates: constraint.candidates(),
+            message: constraint.message(),
+            func: constraint.contains_func(),
+            err,
+        });
+        self
+    }
+    // Function that makes it less verbose to change settings of internal `InputBuilder`.
+    pub(crate) fn internal<F>(self, with: F) -> Self
+    where
+        F: FnOnce(InputBuilder<String>) -> InputBuilder<String>,
+    {
+        Self {
+            builder: with(self.builder),
+            ..self
+        }
+    }
+    pub(crate) fn parse(&self, input: &str, attempt: usize) -> Result<T, Rejection> {
+        let input = input.trim();
+        let reject = |err: Option<&String>, message: Message| Rejection {
+            msg: match err {
+                Some(err) => render(err, |name| placeholder(name, input, &self.builder, attempt)),
+                None => translated(&self.builder, &message),
+            },
+            suggestion: None,
+        };
+        let items = match &self.pattern {
+            Some(pattern) => match_pattern(pattern, input).ok_or_else(|| {
+                reject(
+                    self.builder.err.as_ref(),
+                    Message::Pattern(pattern.join("{}")),
+                )
+            })?,
+            None => self.separator.split(input),
+        };
+        if items.len() != T::ARITY {
+            return Err(reject(
+                self.builder.err.as_ref(),
+                Message::ValueCount(T::ARITY, items.len()),
+            ));
+        }
+        let value = T::parse_items(&items).map_err(|(index, kind)| {
+            reject(
+                self.builder.err.as_ref(),
+                Message::NotValidAt(index + 1, kind),
+            )
+        })?;
+        match self.tests.iter().find(|test| !(test.func)(&value)) {
+            Some(test) => Err(reject(
+                test.err.as_ref().or(self.builder.err.as_ref()),
+                test.message.clone().unwrap_or(Message::Invalid),
+            )),
+            None => Ok(value),
+        }
+    }
+}
+
+impl<T: Tuple> InputBuild<T> for TupleBuilder<T> {
+    fn msg(self, msg: impl ToString) -> Self {
+        self.internal(|x| x.msg(msg))
+    }
+    fn repeat_msg(self, msg: impl ToString) -> Self {
+        self.internal(|x| x.repeat_msg(msg))
+    }
+    fn err(self, err: impl ToString) -> Self {
+        self.internal(|x| x.err(err))
+    }
+    fn add_test<F: Fn(&T) -> bool + 'static>(self, test: F) -> Self {
+        self.test_err_opt(Rc::new(test), None)
+    }
+    fn add_err_test<F>(self, test: F, err: impl ToString) -> Self
+    where
+        F: Fn(&T) -> bool + 'static,
+    {
+        self.test_err_opt(Rc::new(test), Some(err.to_string()))
+    }
+    fn clear_tests(mut self) -> Self {
+        self.tests = Vec::new();
+        self
+    }
+    // Tuples do not implement `FromStr`, so this can not be called.
+    fn err_match<F>(self, _: F) -> Self
+    where
+        T: FromStr,
+        F: Fn(&T::Err) -> Option<String> + 'static,
+    {
+        self
+    }
+    fn inside<U: InsideFunc<T>>(self, constraint: U) -> Self {
+        self.inside_err_opt(constraint, None)
+    }
+    fn inside_err<U: InsideFunc<T>>(self, constraint: U, err: impl ToString) -> Self {
+        self.inside_err_opt(constraint, Some(err.to_string()))
+    }
+    fn toggle_msg_repeat(self) -> Self {
+        self.internal(InputBuild::toggle_msg_repeat)
+    }
+
+    fn prompting_on(self, prompt_output: RefCell<Box<dyn Write>>) -> Self {
+        self.internal(|x| x.prompting_on(prompt_output))
+    }
+
+    fn prompting_on_stderr(self) -> Self {
+        self.internal(InputBuild::prompting_on_stderr)
+    }
+
+    fn errors_on(self, err_output: RefCell<Box<dyn Write>>) -> Self {
+        self.internal(|x| x.errors_on(err_output))
+    }
+
+    fn errors_on_stderr(self) -> Self {
+        self.internal(InputBuild::errors_on_stderr)
+    }
+
+    fn reading_from(self, input: RefCell<Box<dyn BufRead>>) -> Self {
+        self.internal(|x| x.reading_from(input))
+    }
+
+    fn use_tty(self) -> Self {
+        self.internal(InputBuild::use_tty)
+    }
+
+    fn history(self, history: impl Into<History>) -> Self {
+        self.internal(|x| x.history(history))
+    }
+
+    fn completer(self, completer: impl Completer + 'static) -> Self {
+        self.internal(|x| x.completer(completer))
+    }
+
+    fn translate(self, translate: impl Translate + 'static) -> Self {
+        self.internal(|x| x.translate(translate))
+    }
+
+    fn max_attempts(self, attempts: usize) -> Self {
+        self.internal(|x| x.max_attempts(attempts))
+    }
+
+    fn theme(self, theme: Theme) -> Self {
+        self.internal(|x| x.theme(theme))
+    }
+
+    fn initial_text(self, text: impl ToString) -> Self {
+        self.internal(|x| x.initial_text(text))
+    }
+
+    fn batch(self, batch: bool) -> Self {
+        self.internal(|x| x.batch(batch))
+    }
+
+    fn lossy_utf8(self) -> Self {
+        self.internal(InputBuild::lossy_utf8)
+    }
+}
+
+impl<T: Tuple> Default for TupleBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Tuple> Clone for TupleBuilder<T> {
+    fn clone(&self) -> Self {
+        Self {
+            builder: self.builder.clone(),
+            separator: self.separator.clone(),
+            pattern: self.pattern.clone(),
+            tests: self.tests.clone(),
+        }
+    }
+}